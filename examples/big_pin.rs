@@ -19,7 +19,7 @@ fn main() -> iced::Result {
 
 #[derive(Debug, Clone)]
 enum Message {
-    Next,
+    Set(f32),
 }
 
 struct Dashboard {
@@ -34,19 +34,37 @@ impl Dashboard {
             ..Default::default()
         });
 
-        let ticks = MajorMinor::boxed(0.0, 25.0, 5.0, 0.30);
-        let mut gauge = Gauge::new(0.0, 100.0, 0.30, 0.60, Closing::None, ticks, style);
-        gauge.pin = Box::new(Pins::Hollow);
-        gauge.needle = Box::new(Needles::Triangle);
+        let gauge = Gauge::builder()
+            .range(0.0, 100.0)
+            .length(0.30)
+            .rotate(0.60)
+            .closing(Closing::None)
+            .ticks(MajorMinor::boxed(0.0, 25.0, 5.0, 0.30))
+            .pin(Box::new(Pins::Hollow))
+            .needle(Box::new(Needles::Triangle))
+            .style(style)
+            .build()
+            .expect("valid gauge");
         (Dashboard { gauge }, Task::none())
     }
     fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Set(v) => {
+                self.gauge.update_value(v);
+            }
+        }
         Task::none()
     }
-    fn view(&self) -> Element<Message> {
-        container(Row::new().push(canvas(&self.gauge).width(500).height(500)))
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .into()
+    fn view(&self) -> Element<'_, Message> {
+        container(
+            Row::new().push(
+                canvas(self.gauge.interactive(Message::Set))
+                    .width(500)
+                    .height(500),
+            ),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
     }
 }
@@ -1,13 +1,19 @@
 use crate::Msg::Update;
 use iced::time;
+use iced::time::Instant;
 use iced::widget::{canvas, container, toggler, Column, Row};
 use iced::{Color, Settings};
 use iced::{Element, Length, Subscription, Task, Theme};
+use iced_gauges::animation::Motion;
+use iced_gauges::band::{Band, Zone};
+use iced_gauges::mark::Marks;
 use iced_gauges::needle::Needles;
 use iced_gauges::pin::Pins;
+use iced_gauges::readout::Readout;
 use iced_gauges::round::{Closing, Gauge};
+use iced_gauges::shadow::Shadow;
 use iced_gauges::style::{Appearance, Style, DARK_DEFAULT, LIGHT_DEFAULT};
-use iced_gauges::tick::MajorMinor;
+use iced_gauges::tick::{Auto, MajorMinor, Marked, TickRing};
 use std::time::Duration;
 
 fn main() -> iced::Result {
@@ -34,6 +40,7 @@ enum State {
 #[derive(Debug)]
 enum Msg {
     Update,
+    Frame(Instant),
     ThemeChange(bool),
 }
 
@@ -46,11 +53,18 @@ struct Dashboard {
 impl Dashboard {
     fn new() -> (Self, Task<Msg>) {
         let ticks = MajorMinor::boxed(0.0, 5.0, 1.0, 0.30);
-        let small_ticks = MajorMinor::boxed(0.0, 25.0, 5.0, 0.30);
+        let small_ticks = Auto::boxed(0.30);
+        let bar_ticks = Box::new(
+            MajorMinor::new(0.0, 5.0, 1.0, 0.30)
+                .with_marks(Marks::Rectangle, Marks::Dot)
+                .with_lengths(0.30, 0.08),
+        );
         let light = Appearance {
             pin_border_width_ratio: 0.1,
             pin_diameter_ratio: 0.5,
             pin_color: Color::from_rgb(0.5, 0.5, 0.5),
+            needle_shadow: Some(Shadow::DEFAULT),
+            pin_shadow: Some(Shadow::DEFAULT),
             ..LIGHT_DEFAULT
         };
         let dark = Appearance {
@@ -60,75 +74,96 @@ impl Dashboard {
             ..DARK_DEFAULT
         };
         let style = Style::Themed { light, dark };
+        let motion = Motion::Spring {
+            stiffness: 60.0,
+            damping: 9.0,
+        };
         (
             Dashboard {
                 gauge: vec![
-                    Gauge::new(
-                        0.0,
-                        85.0,
-                        0.90,
-                        0.30,
-                        Closing::Segment,
-                        ticks.clone(),
-                        style,
-                    )
-                    .with_name("Speed")
-                    .with_needle(Box::new(Needles::Arrow))
-                    .with_pin(Box::new(Pins::Hollow)),
-                    Gauge::new(
-                        0.0,
-                        125.0,
-                        0.35,
-                        0.60,
-                        Closing::None,
-                        small_ticks.clone(),
-                        style,
-                    ),
-                    Gauge::new(
-                        0.0,
-                        100.0,
-                        0.90,
-                        0.30,
-                        Closing::Segment,
-                        small_ticks.clone(),
-                        style,
-                    ),
-                    Gauge::new(
-                        0.0,
-                        100.0,
-                        0.35,
-                        0.40,
-                        Closing::Sector,
-                        small_ticks.clone(),
-                        style,
-                    )
-                    .with_needle(Box::new(Needles::Arrow)),
-                    Gauge::new(
-                        0.0,
-                        100.0,
-                        0.35,
-                        0.90,
-                        Closing::None,
-                        small_ticks.clone(),
-                        style,
-                    ),
-                    Gauge::new(
-                        0.0,
-                        100.0,
-                        0.75,
-                        0.0,
-                        Closing::None,
-                        small_ticks.clone(),
-                        style,
-                    ),
-                    Gauge::new(
-                        0.0,
-                        85.0,
-                        0.90,
-                        0.30,
-                        Closing::Segment,
-                        ticks.clone(),
-                        Style::Themed {
+                    Gauge::builder()
+                        .range(0.0, 85.0)
+                        .length(0.90)
+                        .rotate(0.30)
+                        .closing(Closing::Segment)
+                        .motion(motion)
+                        .ticks(ticks.clone())
+                        .style(style)
+                        .name("Speed")
+                        .band(Band::new(60.0..70.0, Zone::Caution))
+                        .band(Band::new(70.0..85.0, Zone::Danger))
+                        .ring(TickRing::new(
+                            MajorMinor::boxed(0.0, 20.0, 10.0, 0.12),
+                            1.609_344,
+                        ))
+                        .readout(Readout::default().with_unit(" mph"))
+                        .needle(Box::new(Needles::Arrow))
+                        .pin(Box::new(Pins::Hollow))
+                        .build()
+                        .expect("valid gauge"),
+                    Gauge::builder()
+                        .range(0.0, 125.0)
+                        .length(0.35)
+                        .rotate(0.60)
+                        .closing(Closing::None)
+                        .motion(motion)
+                        .ticks(small_ticks.clone())
+                        .style(style)
+                        .build()
+                        .expect("valid gauge"),
+                    Gauge::builder()
+                        .range(0.0, 100.0)
+                        .length(0.90)
+                        .rotate(0.30)
+                        .closing(Closing::Segment)
+                        .motion(motion)
+                        .ticks(small_ticks.clone())
+                        .style(style)
+                        .build()
+                        .expect("valid gauge"),
+                    Gauge::builder()
+                        .range(0.0, 100.0)
+                        .length(0.35)
+                        .rotate(0.40)
+                        .closing(Closing::Sector)
+                        .pin(Box::new(Pins::Screw))
+                        .motion(motion)
+                        .ticks(small_ticks.clone())
+                        .style(style)
+                        .needle(Box::new(Needles::Arrow))
+                        .build()
+                        .expect("valid gauge"),
+                    Gauge::builder()
+                        .range(0.0, 100.0)
+                        .length(0.35)
+                        .rotate(0.90)
+                        .closing(Closing::None)
+                        .motion(motion)
+                        .ticks(small_ticks.clone())
+                        .style(style)
+                        .build()
+                        .expect("valid gauge"),
+                    Gauge::builder()
+                        .range(0.0, 100.0)
+                        .length(0.75)
+                        .rotate(0.0)
+                        .oval(true)
+                        .needle(Box::new(Needles::Baton))
+                        .pin(Box::new(Pins::Beveled))
+                        .closing(Closing::None)
+                        .motion(motion)
+                        .ticks(small_ticks.clone())
+                        .style(style)
+                        .build()
+                        .expect("valid gauge"),
+                    Gauge::builder()
+                        .range(0.0, 85.0)
+                        .length(0.90)
+                        .rotate(0.30)
+                        .closing(Closing::Segment)
+                        .motion(motion)
+                        .ticks(bar_ticks)
+                        .style(Style::Themed {
                             light: Appearance {
                                 tick_labels: false,
                                 pin_diameter_ratio: 0.8,
@@ -139,10 +174,11 @@ impl Dashboard {
                                 pin_diameter_ratio: 0.8,
                                 ..dark
                             },
-                        },
-                    )
-                    .with_needle(Box::new(Needles::Triangle))
-                    .with_pin(Box::new(Pins::Solid)),
+                        })
+                        .needle(Box::new(Needles::Triangle))
+                        .pin(Box::new(Pins::Solid))
+                        .build()
+                        .expect("valid gauge"),
                 ],
                 state: State::Accel(0.0),
                 dark_mode: false,
@@ -156,9 +192,11 @@ impl Dashboard {
             Update => match self.state {
                 State::Accel(v) => {
                     if v < 85.0 {
-                        let v = v + 1.0;
+                        let v = v + 5.0;
                         self.state = State::Accel(v);
-                        self.gauge.iter_mut().for_each(|g| g.update_value(v));
+                        for g in self.gauge.iter_mut() {
+                            g.update_value(v);
+                        }
                     } else {
                         self.state = State::Decel(85.0);
                     }
@@ -166,15 +204,24 @@ impl Dashboard {
                 State::Decel(v) => {
                     if v <= 0.0 {
                         self.state = State::Stop;
-                        self.gauge.iter_mut().for_each(|g| g.update_value(0.0));
+                        for g in self.gauge.iter_mut() {
+                            g.update_value(0.0);
+                        }
                     } else {
-                        let v = v - 1.0;
+                        let v = v - 5.0;
                         self.state = State::Decel(v);
-                        self.gauge.iter_mut().for_each(|g| g.update_value(v));
+                        for g in self.gauge.iter_mut() {
+                            g.update_value(v);
+                        }
                     }
                 }
                 State::Stop => {}
             },
+            Msg::Frame(now) => {
+                for g in self.gauge.iter_mut() {
+                    g.advance(now);
+                }
+            }
             Msg::ThemeChange(b) => {
                 self.dark_mode = b;
                 self.gauge.iter().for_each(|g| g.repaint());
@@ -183,7 +230,7 @@ impl Dashboard {
         Task::none()
     }
 
-    fn view(&self) -> Element<Msg> {
+    fn view(&self) -> Element<'_, Msg> {
         // row
         //  col
         //   gauge 200x200
@@ -215,7 +262,7 @@ impl Dashboard {
             .push(canvas(gauges.next().unwrap()).width(200).height(200))
             .push(canvas(gauges.next().unwrap()).width(200).height(200))
             .push(canvas(gauges.next().unwrap()).width(200).height(200))
-            .push(canvas(gauges.next().unwrap()).width(200).height(200))
+            .push(canvas(gauges.next().unwrap()).width(300).height(200))
             .push(canvas(gauges.next().unwrap()).width(200).height(200));
 
         let row = Column::new().push(bar).push(top).push(bottom);
@@ -236,9 +283,11 @@ impl Dashboard {
     fn subscription(&self) -> Subscription<Msg> {
         use State::*;
 
-        match self.state {
+        let needles = self.gauge.iter().map(|g| g.subscription().map(Msg::Frame));
+        let steps = match self.state {
             Stop => Subscription::none(),
-            Accel(_) | Decel(_) => time::every(Duration::from_millis(100)).map(|_| Update),
-        }
+            Accel(_) | Decel(_) => time::every(Duration::from_millis(500)).map(|_| Update),
+        };
+        Subscription::batch(needles.chain(Some(steps)))
     }
 }
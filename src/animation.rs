@@ -0,0 +1,201 @@
+use iced::time::{Duration, Instant};
+
+/// Progress curves for timed needle movement
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    #[default]
+    EaseOut,
+    EaseInOut,
+    /// Eases out past the target and settles back onto it
+    Back,
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Map linear progress 0.0 -- 1.0 onto the curve
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::Back => {
+                const C1: f32 = 1.70158;
+                const C3: f32 = C1 + 1.0;
+                1.0 + C3 * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2)
+            }
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+/// How the needle travels to a new value
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Motion {
+    /// Jump straight to the new value
+    #[default]
+    Instant,
+    /// Ease to the new value over a fixed duration
+    Tween { duration: Duration, easing: Easing },
+    /// Damped spring pulling the needle onto the new value
+    /// Low damping overshoots and settles like a mechanical needle
+    Spring { stiffness: f32, damping: f32 },
+}
+
+/// Longest time integrated in a single spring step
+const SPRING_STEP: f32 = 1.0 / 240.0;
+
+/// Animated unit value driven by externally supplied instants
+#[derive(Clone, Debug)]
+pub struct Animation {
+    motion: Motion,
+    from: f32,
+    target: f32,
+    current: f32,
+    velocity: f32,
+    /// Distance and speed under which a spring is considered at rest
+    precision: f32,
+    /// Set by the first advance after a new target
+    started: Option<Instant>,
+    last: Option<Instant>,
+    animating: bool,
+}
+
+impl Animation {
+    pub fn new(motion: Motion, value: f32, precision: f32) -> Self {
+        Self {
+            motion,
+            from: value,
+            target: value,
+            current: value,
+            velocity: 0.0,
+            precision,
+            started: None,
+            last: None,
+            animating: false,
+        }
+    }
+
+    /// Start moving toward the target from the current value
+    pub fn retarget(&mut self, target: f32) {
+        self.target = target;
+        if let Motion::Instant = self.motion {
+            self.current = target;
+            self.velocity = 0.0;
+            self.animating = false;
+        } else {
+            self.from = self.current;
+            self.started = None;
+            self.last = None;
+            self.animating = self.current != target;
+        }
+    }
+
+    /// Move the animation to the instant, returns true if the value changed
+    pub fn advance(&mut self, now: Instant) -> bool {
+        if !self.animating {
+            return false;
+        }
+        let started = *self.started.get_or_insert(now);
+        let last = self.last.replace(now).unwrap_or(now);
+        let before = self.current;
+
+        match self.motion {
+            Motion::Instant => self.current = self.target,
+            Motion::Tween { duration, easing } => {
+                let t = if duration.is_zero() {
+                    1.0
+                } else {
+                    now.saturating_duration_since(started).as_secs_f32() / duration.as_secs_f32()
+                };
+                self.current = self.from + (self.target - self.from) * easing.apply(t);
+                if t >= 1.0 {
+                    self.current = self.target;
+                    self.animating = false;
+                }
+            }
+            Motion::Spring { stiffness, damping } => {
+                let mut dt = now.saturating_duration_since(last).as_secs_f32();
+                while dt > 0.0 {
+                    let h = dt.min(SPRING_STEP);
+                    let force = stiffness * (self.target - self.current) - damping * self.velocity;
+                    self.velocity += force * h;
+                    self.current += self.velocity * h;
+                    dt -= h;
+                }
+                if (self.target - self.current).abs() < self.precision
+                    && self.velocity.abs() < self.precision
+                {
+                    self.current = self.target;
+                    self.velocity = 0.0;
+                    self.animating = false;
+                }
+            }
+        }
+        self.current != before
+    }
+
+    /// Value to display
+    pub fn current(&self) -> f32 {
+        self.current
+    }
+
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    pub fn is_animating(&self) -> bool {
+        self.animating
+    }
+}
+
+#[test]
+fn tween_reaches_target_at_duration() {
+    let start = Instant::now();
+    let mut a = Animation::new(
+        Motion::Tween {
+            duration: Duration::from_millis(100),
+            easing: Easing::Linear,
+        },
+        0.0,
+        0.01,
+    );
+    a.retarget(10.0);
+    a.advance(start);
+    a.advance(start + Duration::from_millis(50));
+    assert_eq!(a.current(), 5.0);
+    a.advance(start + Duration::from_millis(150));
+    assert_eq!(a.current(), 10.0);
+    assert!(!a.is_animating());
+}
+
+#[test]
+fn underdamped_spring_overshoots_and_settles() {
+    let start = Instant::now();
+    let mut a = Animation::new(
+        Motion::Spring {
+            stiffness: 200.0,
+            damping: 8.0,
+        },
+        0.0,
+        0.01,
+    );
+    a.retarget(10.0);
+    let mut peak: f32 = 0.0;
+    for ms in (0..5000).step_by(16) {
+        a.advance(start + Duration::from_millis(ms));
+        peak = peak.max(a.current());
+    }
+    assert!(peak > 10.0);
+    assert_eq!(a.current(), 10.0);
+    assert!(!a.is_animating());
+}
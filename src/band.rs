@@ -0,0 +1,107 @@
+use std::ops::Range;
+
+use iced::widget::canvas::path::Builder;
+use iced::widget::canvas::{stroke, Frame, LineCap, Stroke};
+use iced::Color;
+
+use crate::scale::Dial;
+use crate::style::Appearance;
+use crate::Ellipse;
+
+/// Meaning of a band, which selects its color from the [`Appearance`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Zone {
+    Normal,
+    Caution,
+    Danger,
+    Custom(Color),
+}
+
+/// Colored arc marking a range of unit values along the tick ring
+#[derive(Clone, Copy, Debug)]
+pub struct Band {
+    /// Unit value the band starts at
+    pub start: f32,
+    /// Unit value the band ends at
+    pub end: f32,
+    pub zone: Zone,
+    /// Inner edge as ratio of the tick ring radius
+    pub inner_ratio: f32,
+    /// Outer edge as ratio of the tick ring radius
+    pub outer_ratio: f32,
+}
+
+impl Band {
+    pub fn new(range: Range<f32>, zone: Zone) -> Self {
+        Band {
+            start: range.start,
+            end: range.end,
+            zone,
+            inner_ratio: 0.9,
+            outer_ratio: 1.0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_radii(mut self, inner_ratio: f32, outer_ratio: f32) -> Self {
+        self.inner_ratio = inner_ratio;
+        self.outer_ratio = outer_ratio;
+        self
+    }
+
+    pub fn color(&self, style: &Appearance) -> Color {
+        match self.zone {
+            Zone::Normal => style.band_normal_color,
+            Zone::Caution => style.band_caution_color,
+            Zone::Danger => style.band_danger_color,
+            Zone::Custom(c) => c,
+        }
+    }
+
+    /// Unit values the band covers within the dial range, None when it lies outside
+    pub fn span(&self, dial: &Dial) -> Option<(f32, f32)> {
+        let start = self.start.min(self.end).max(dial.min);
+        let end = self.start.max(self.end).min(dial.max);
+        (start < end).then_some((start, end))
+    }
+
+    /// Draw the band onto a frame centered and rotated to the gauge minimum
+    /// The ring is the tick border in pixels
+    pub fn draw(&self, frame: &mut Frame, style: &Appearance, dial: &Dial, ring: &Ellipse) {
+        let Some((start, end)) = self.span(dial) else {
+            return;
+        };
+        let mid = ring.scaled((self.inner_ratio + self.outer_ratio) / 2.0);
+        let width = ring.min_radius() * (self.outer_ratio - self.inner_ratio).abs();
+
+        let mut builder = Builder::new();
+        mid.trace(&mut builder, dial.angle(start).0, dial.angle(end).0);
+        frame.stroke(
+            &builder.build(),
+            Stroke {
+                width,
+                style: stroke::Style::Solid(self.color(style)),
+                line_cap: LineCap::Butt,
+                ..Stroke::default()
+            },
+        );
+    }
+}
+
+#[test]
+fn span_is_clamped_to_the_dial() {
+    use crate::scale::Logarithmic;
+    use iced::Radians;
+
+    let dial = Dial {
+        min: 1.0,
+        max: 1000.0,
+        length: Radians(4.0),
+        rotate: Radians(0.0),
+        scale: &Logarithmic,
+    };
+    let band = |range| Band::new(range, Zone::Normal);
+    assert_eq!(band(0.0..10.0).span(&dial), Some((1.0, 10.0)));
+    assert_eq!(band(500.0..9000.0).span(&dial), Some((500.0, 1000.0)));
+    assert_eq!(band(2000.0..3000.0).span(&dial), None);
+}
@@ -0,0 +1,225 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+/// Turns tick values into label text, an empty string draws no label
+pub trait LabelFormatter {
+    fn format(&self, value: f32) -> String;
+}
+
+impl<F: Fn(f32) -> String> LabelFormatter for F {
+    fn format(&self, value: f32) -> String {
+        self(value)
+    }
+}
+
+/// Fixed number of decimals with optional digit grouping and unit suffix
+#[derive(Clone, Debug, Default)]
+pub struct Fixed {
+    pub precision: usize,
+    /// Inserted between groups of three integer digits
+    pub separator: Option<char>,
+    pub unit: String,
+}
+
+impl Fixed {
+    pub fn new(precision: usize) -> Self {
+        Fixed {
+            precision,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    #[must_use]
+    pub fn with_unit(mut self, unit: &str) -> Self {
+        self.unit = unit.to_string();
+        self
+    }
+}
+
+fn group(digits: &str, separator: char) -> String {
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(d) => ("-", d),
+        None => ("", digits),
+    };
+    let mut out = String::from(sign);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(separator);
+        }
+        out.push(c);
+    }
+    out
+}
+
+impl LabelFormatter for Fixed {
+    fn format(&self, value: f32) -> String {
+        let text = format!("{value:.*}", self.precision);
+        let text = match self.separator {
+            Some(sep) => match text.split_once('.') {
+                Some((int, frac)) => format!("{}.{frac}", group(int, sep)),
+                None => group(&text, sep),
+            },
+            None => text,
+        };
+        format!("{text}{}", self.unit)
+    }
+}
+
+/// Scaled to an SI prefix, such as 1500 as 1.5k
+#[derive(Clone, Debug, Default)]
+pub struct Si {
+    pub precision: usize,
+    pub unit: String,
+}
+
+impl Si {
+    pub fn new(precision: usize, unit: &str) -> Self {
+        Si {
+            precision,
+            unit: unit.to_string(),
+        }
+    }
+}
+
+const SI_PREFIXES: [(f32, &str); 8] = [
+    (1.0E9, "G"),
+    (1.0E6, "M"),
+    (1.0E3, "k"),
+    (1.0, ""),
+    (1.0E-3, "m"),
+    (1.0E-6, "µ"),
+    (1.0E-9, "n"),
+    (1.0E-12, "p"),
+];
+
+impl LabelFormatter for Si {
+    fn format(&self, value: f32) -> String {
+        let (scale, prefix) = if value == 0.0 {
+            (1.0, "")
+        } else {
+            SI_PREFIXES
+                .iter()
+                .find(|(scale, _)| value.abs() >= *scale * (1.0 - 1.0E-6))
+                .copied()
+                .unwrap_or(SI_PREFIXES[SI_PREFIXES.len() - 1])
+        };
+        format!("{:.*}{prefix}{}", self.precision, value / scale, self.unit)
+    }
+}
+
+/// Ratio shown as a percentage, such as 0.25 as 25%
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Percent {
+    pub precision: usize,
+}
+
+impl LabelFormatter for Percent {
+    fn format(&self, value: f32) -> String {
+        format!("{:.*}%", self.precision, value * 100.0)
+    }
+}
+
+/// Text for listed values, other values are left unlabeled
+#[derive(Clone, Debug, Default)]
+pub struct Lookup {
+    pub entries: Vec<(f32, String)>,
+}
+
+impl Lookup {
+    pub fn new<S: Into<String>>(entries: impl IntoIterator<Item = (f32, S)>) -> Self {
+        Lookup {
+            entries: entries.into_iter().map(|(v, s)| (v, s.into())).collect(),
+        }
+    }
+}
+
+impl LabelFormatter for Lookup {
+    fn format(&self, value: f32) -> String {
+        let tolerance = 1.0E-4 * value.abs().max(1.0);
+        self.entries
+            .iter()
+            .find(|(v, _)| (v - value).abs() <= tolerance)
+            .map(|(_, s)| s.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Rotation of tick labels around their anchor
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LabelOrientation {
+    /// Level with the screen at every position
+    #[default]
+    Upright,
+    /// Running along the radius, flipped on the left so it never reads upside down
+    Radial,
+    /// Following the arc with the top of the text facing outward
+    Tangential,
+}
+
+impl LabelOrientation {
+    /// Frame rotation for a label at the angle, in a frame rotated by base
+    pub fn rotation(&self, angle: f32, base: f32) -> f32 {
+        match self {
+            LabelOrientation::Upright => -base,
+            LabelOrientation::Radial if (angle + base).cos() < 0.0 => angle + PI,
+            LabelOrientation::Radial => angle,
+            LabelOrientation::Tangential => angle + FRAC_PI_2,
+        }
+    }
+}
+
+/// Where tick labels sit relative to the tick ring
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LabelPlacement {
+    /// Inside the inner end of the major ticks
+    #[default]
+    Inside,
+    /// Outside the tick ring
+    Outside,
+    /// Centered on the tick ring
+    OnRing,
+}
+
+impl LabelPlacement {
+    /// Label center as ratio of the ring radius
+    /// Tick length and label size are ratios of the ring radius as well
+    pub fn anchor_ratio(&self, tick_length: f32, size_ratio: f32) -> f32 {
+        match self {
+            LabelPlacement::Inside => 1.0 - tick_length - size_ratio,
+            LabelPlacement::Outside => 1.0 + size_ratio,
+            LabelPlacement::OnRing => 1.0,
+        }
+    }
+}
+
+#[test]
+fn builtin_formatters() {
+    let fixed = Fixed::new(1).with_separator(',').with_unit(" rpm");
+    assert_eq!(fixed.format(12345.0), "12,345.0 rpm");
+    assert_eq!(fixed.format(-999.0), "-999.0 rpm");
+    assert_eq!(Si::new(1, "Pa").format(1500.0), "1.5kPa");
+    assert_eq!(Si::new(0, "V").format(0.0), "0V");
+    assert_eq!(Percent { precision: 0 }.format(0.25), "25%");
+    let fuel = Lookup::new([(0.0, "E"), (0.5, "½"), (1.0, "F")]);
+    assert_eq!(fuel.format(0.5), "½");
+    assert_eq!(fuel.format(0.25), "");
+    assert_eq!((|v: f32| format!("{v}x")).format(2.0), "2x");
+}
+
+#[test]
+fn orientation_rotations() {
+    let base = 0.75 * PI;
+    assert_eq!(LabelOrientation::Upright.rotation(1.0, base), -base);
+    // pointing right on screen reads outward, pointing left is flipped
+    assert_eq!(
+        LabelOrientation::Radial.rotation(1.25 * PI, base),
+        1.25 * PI
+    );
+    assert_eq!(LabelOrientation::Radial.rotation(0.0, base), PI);
+    assert_eq!(LabelOrientation::Tangential.rotation(0.0, base), FRAC_PI_2);
+}
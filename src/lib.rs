@@ -1,10 +1,21 @@
 //! Iced Gauges
-pub use crate::tick::Tick;
+pub use crate::label::LabelFormatter;
+pub use crate::scale::Scale;
+pub use crate::tick::{Marked, Tick};
 pub use crate::util::Ellipse;
 
+pub mod animation;
+pub mod band;
+pub mod label;
+pub mod mark;
 pub mod needle;
 pub mod pin;
+pub mod readout;
 pub mod round;
+pub mod scale;
+pub mod shadow;
 pub mod style;
+pub mod svg;
+pub mod telltale;
 pub mod tick;
 mod util;
@@ -0,0 +1,73 @@
+use iced::widget::canvas::path::Builder;
+use iced::widget::canvas::{stroke, Frame, LineCap, Path, Stroke};
+use iced::{Color, Point, Size, Vector};
+
+/// Shape drawn at a tick position
+pub trait TickMark {
+    /// Draw the mark spanning from the inner to the outer point along the radius
+    /// Width is the tick stroke width in pixels
+    fn draw(&self, frame: &mut Frame, inner: Point, outer: Point, width: f32, color: Color);
+}
+
+#[derive(Clone)]
+pub enum Marks {
+    /// Round capped line of the tick width
+    Line,
+    /// Circle filling the tick length
+    Dot,
+    /// Triangle pointing to the center, half as wide as it is long
+    Wedge,
+    /// Square ended bar of the tick width
+    Rectangle,
+    /// Filled path with the inner end at (0, 0) and the outer end at (1, 0),
+    /// y is measured in tick widths
+    Custom(Path),
+}
+
+impl TickMark for Marks {
+    fn draw(&self, frame: &mut Frame, inner: Point, outer: Point, width: f32, color: Color) {
+        let along = outer - inner;
+        let length = along.x.hypot(along.y);
+        match self {
+            Marks::Line => frame.stroke(
+                &Path::line(inner, outer),
+                Stroke {
+                    width,
+                    style: stroke::Style::Solid(color),
+                    line_cap: LineCap::Round,
+                    ..Stroke::default()
+                },
+            ),
+            Marks::Dot => frame.fill(&Path::circle(inner + along * 0.5, length / 2.0), color),
+            Marks::Wedge => {
+                let mut b = Builder::new();
+                b.move_to(inner);
+                b.line_to(outer + across(along, length / 4.0));
+                b.line_to(outer - across(along, length / 4.0));
+                b.close();
+                frame.fill(&b.build(), color);
+            }
+            Marks::Rectangle => frame.with_save(|frame| {
+                frame.translate(Vector::new(inner.x, inner.y));
+                frame.rotate(along.y.atan2(along.x));
+                frame.fill_rectangle(
+                    Point::new(0.0, -width / 2.0),
+                    Size::new(length, width),
+                    color,
+                );
+            }),
+            Marks::Custom(path) => frame.with_save(|frame| {
+                frame.translate(Vector::new(inner.x, inner.y));
+                frame.rotate(along.y.atan2(along.x));
+                frame.scale_nonuniform(Vector::new(length, width));
+                frame.fill(path, color);
+            }),
+        }
+    }
+}
+
+/// Vector of the given length perpendicular to the direction
+fn across(direction: Vector, length: f32) -> Vector {
+    let norm = direction.x.hypot(direction.y).max(f32::EPSILON);
+    Vector::new(-direction.y, direction.x) * (length / norm)
+}
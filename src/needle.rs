@@ -1,79 +1,315 @@
 use iced::widget::canvas::path::Builder;
 use iced::widget::canvas::{stroke, Fill, Frame, LineCap, Path, Stroke};
-use iced::{Color, Point};
+use iced::{Color, Point, Size};
+
+use crate::animation::{Animation, Motion};
+use crate::round::Reading;
+use crate::shadow::Outline;
+use crate::style::Appearance;
+use crate::svg::{self, Segment};
 
 pub trait Needle {
-    fn path(&self, gauge_radius: f32) -> Path;
-    fn tip(&self, gauge_radius: f32) -> Point {
-        Point::new(0.5 * gauge_radius, 0.0)
+    /// Shape pointing along the x axis from the pivot at the origin to the tip at (length, 0)
+    /// Width is the breadth of the widest part, both are in pixels
+    fn path(&self, length: f32, width: f32) -> Path;
+    /// Shape casting the needle shadow, the filled path unless the needle is drawn as lines
+    fn shadow(&self, length: f32, width: f32) -> Outline {
+        Outline::Fill(self.path(length, width))
     }
-    fn stroke<'a>(&self, width: f32) -> Stroke<'a> {
+    fn stroke<'a>(&self, width: f32, color: Color) -> Stroke<'a> {
         Stroke {
             width,
-            style: stroke::Style::Solid(Color::BLACK),
+            style: stroke::Style::Solid(color),
             line_cap: LineCap::Round,
             ..Stroke::default()
         }
     }
-    fn draw(&self, gauge_radius: f32, _value: f32, frame: &mut Frame) {
-        let path = self.path(gauge_radius);
-        frame.fill(&path, Fill::default());
-        // let tip = self.tip(gauge_radius);
-        // frame.translate(Vector::new(tip.x, tip.y));
-        // frame.fill_text(format!("{}", value));
+    /// Fill the path with the color, which is the hand color or else the style needle color,
+    /// and outline it as the style sets
+    fn draw(
+        &self,
+        gauge_radius: f32,
+        _value: f32,
+        color: Color,
+        style: &Appearance,
+        frame: &mut Frame,
+    ) {
+        let path = self.path(
+            gauge_radius * style.needle_length_ratio,
+            gauge_radius * style.needle_width_ratio,
+        );
+        fill_and_outline(self, &path, gauge_radius, color, style, frame);
+    }
+}
+
+/// Fill the needle path and outline it as the style sets
+fn fill_and_outline(
+    needle: &(impl Needle + ?Sized),
+    path: &Path,
+    gauge_radius: f32,
+    color: Color,
+    style: &Appearance,
+    frame: &mut Frame,
+) {
+    frame.fill(
+        path,
+        Fill {
+            style: stroke::Style::Solid(color),
+            ..Fill::default()
+        },
+    );
+    let width = gauge_radius * style.needle_outline_width_ratio;
+    if width > 0.0 {
+        frame.stroke(path, needle.stroke(width, style.needle_outline_color));
     }
 }
 
 pub enum Needles {
+    /// Narrow bar
     Basic,
     Diamond,
     Arrow,
+    /// Tapering from a tail behind the pivot
     Triangle,
+    /// Blade with a crossguard and a short grip behind the pivot
+    Sword,
+    /// Round ended bar reaching a little behind the pivot
+    Baton,
+    /// Thin stem with a ball at the tip
+    Lollipop,
+    /// Thin pointer balanced by a block behind the pivot
+    Counterweight,
+    /// Outline of a slender diamond in the needle color
+    Skeleton,
+}
+
+/// Closed shape from the tip through the upper points and back through their mirror images
+/// Points are (x, y) as ratios of the needle length and width
+fn mirrored(length: f32, width: f32, upper: &[(f32, f32)]) -> Path {
+    let point = |(x, y): (f32, f32)| Point::new(x * length, y * width);
+    let mut b = Builder::new();
+    b.move_to(Point::new(length, 0.0));
+    for &p in upper {
+        b.line_to(point(p));
+    }
+    for &(x, y) in upper.iter().rev().filter(|(_, y)| *y != 0.0) {
+        b.line_to(point((x, -y)));
+    }
+    b.close();
+    b.build()
+}
+
+/// Line width of the skeleton outline for a needle width
+fn skeleton_line(width: f32) -> f32 {
+    (width / 5.0).max(1.0)
 }
 
 impl Needle for Needles {
-    fn path(&self, gauge_radius: f32) -> Path {
+    fn path(&self, length: f32, width: f32) -> Path {
         match self {
-            Needles::Basic => Path::line(Point::ORIGIN, self.tip(gauge_radius)),
-            Needles::Diamond => {
+            Needles::Basic => mirrored(length, width, &[(1.0, 0.0625), (0.0, 0.0625)]),
+            Needles::Diamond => mirrored(length, width, &[(0.5, 0.5), (0.0, 0.0)]),
+            Needles::Arrow => mirrored(length, width, &[(0.0, 0.5), (0.2, 0.0)]),
+            Needles::Triangle => mirrored(length, width, &[(-0.25, 0.5)]),
+            Needles::Sword => mirrored(
+                length,
+                width,
+                &[
+                    (0.8, 0.25),
+                    (0.12, 0.25),
+                    (0.12, 0.5),
+                    (0.06, 0.5),
+                    (0.06, 0.125),
+                    (-0.15, 0.125),
+                ],
+            ),
+            Needles::Baton => Path::rounded_rectangle(
+                Point::new(-0.1 * length, -width / 2.0),
+                Size::new(1.1 * length, width),
+                (width / 2.0).into(),
+            ),
+            Needles::Lollipop => {
+                let ball = width / 2.0;
                 let mut b = Builder::new();
-                b.move_to(Point::ORIGIN);
-                b.line_to(Point::new(0.25 * gauge_radius, 7.5));
-                b.line_to(self.tip(gauge_radius));
-                b.line_to(Point::new(0.25 * gauge_radius, -7.5));
-                b.line_to(Point::ORIGIN);
-                b.close();
-
+                b.rectangle(
+                    Point::new(0.0, -width / 8.0),
+                    Size::new(length - 2.0 * ball, width / 4.0),
+                );
+                b.circle(Point::new(length - ball, 0.0), ball);
                 b.build()
             }
-            Needles::Arrow => {
-                let inset = gauge_radius / 10.0;
-                let rear_width = 0.25 * gauge_radius / 2.0;
-                let tail = Point::new(inset, 0.0);
-                let mut b = Builder::new();
-                b.move_to(tail);
-                b.line_to(Point::new(10.0, rear_width));
-                b.line_to(self.tip(gauge_radius));
-                b.line_to(Point::new(10.0, -rear_width));
-                b.line_to(tail);
-                b.close();
+            Needles::Counterweight => mirrored(
+                length,
+                width,
+                &[(0.0, 0.15), (-0.15, 0.15), (-0.15, 0.5), (-0.35, 0.5)],
+            ),
+            Needles::Skeleton => mirrored(length, width, &[(0.3, 0.5), (0.0, 0.0)]),
+        }
+    }
 
-                b.build()
-            }
-            Needles::Triangle => {
-                let rear_length = gauge_radius / 5.0;
-                let rear_width = 0.25 * gauge_radius / 5.0;
-                let tail = Point::new(-rear_length, 0.0);
-                let mut b = Builder::new();
-                b.move_to(tail);
-                b.line_to(Point::new(tail.x, rear_width));
-                b.line_to(self.tip(gauge_radius));
-                b.line_to(Point::new(tail.x, -rear_width));
-                b.line_to(tail);
-                b.close();
+    fn shadow(&self, length: f32, width: f32) -> Outline {
+        match self {
+            Needles::Skeleton => Outline::Stroke(self.path(length, width), skeleton_line(width)),
+            _ => Outline::Fill(self.path(length, width)),
+        }
+    }
 
-                b.build()
+    fn draw(
+        &self,
+        gauge_radius: f32,
+        _value: f32,
+        color: Color,
+        style: &Appearance,
+        frame: &mut Frame,
+    ) {
+        let width = gauge_radius * style.needle_width_ratio;
+        let path = self.path(gauge_radius * style.needle_length_ratio, width);
+        match self {
+            Needles::Skeleton => frame.stroke(&path, self.stroke(skeleton_line(width), color)),
+            _ => fill_and_outline(self, &path, gauge_radius, color, style, frame),
+        }
+    }
+}
+
+/// Needle artwork given as SVG path data
+///
+/// The shape is turned so the tip lies along the needle and scaled so the pivot to tip
+/// distance matches the needle length, keeping the drawn proportions.
+#[derive(Clone, Debug)]
+pub struct SvgNeedle {
+    segments: Vec<Segment>,
+    pivot: Point,
+    tip: Point,
+}
+
+impl SvgNeedle {
+    pub fn new(d: &str, pivot: Point, tip: Point) -> Result<Self, svg::Error> {
+        let reach = pivot.distance(tip);
+        if !reach.is_finite() || reach == 0.0 {
+            return Err(svg::Error::TipAtPivot);
+        }
+        Ok(SvgNeedle {
+            segments: svg::parse(d)?,
+            pivot,
+            tip,
+        })
+    }
+
+    /// Center of rotation, in path coordinates
+    pub fn pivot(&self) -> Point {
+        self.pivot
+    }
+
+    /// Point of the artwork placed at the needle tip, in path coordinates
+    pub fn tip(&self) -> Point {
+        self.tip
+    }
+
+    /// Path coordinates to needle coordinates, with the tip at (length, 0)
+    fn place(&self, p: Point, length: f32) -> Point {
+        let reach = self.tip - self.pivot;
+        let scale = length / reach.x.hypot(reach.y);
+        let (sin, cos) = reach.y.atan2(reach.x).sin_cos();
+        let v = (p - self.pivot) * scale;
+        Point::new(cos * v.x + sin * v.y, cos * v.y - sin * v.x)
+    }
+}
+
+impl Needle for SvgNeedle {
+    fn path(&self, length: f32, _width: f32) -> Path {
+        let place = |p| self.place(p, length);
+        let mut b = Builder::new();
+        for segment in &self.segments {
+            match *segment {
+                Segment::Move(p) => b.move_to(place(p)),
+                Segment::Line(p) => b.line_to(place(p)),
+                Segment::Quadratic(c, p) => b.quadratic_curve_to(place(c), place(p)),
+                Segment::Cubic(c1, c2, p) => b.bezier_curve_to(place(c1), place(c2), place(p)),
+                Segment::Close => b.close(),
             }
         }
+        b.build()
+    }
+}
+
+/// A needle on the dial showing its own value
+pub struct Hand {
+    pub needle: Box<dyn Needle>,
+    /// Fill color, the style needle color when not set
+    pub color: Option<Color>,
+    /// Drawing order, higher hands are drawn over lower ones
+    pub z: i32,
+    /// Current unit value
+    pub(crate) value: f32,
+    /// Range status of the last update
+    pub(crate) reading: Reading,
+    /// Needle travel toward the current value
+    pub(crate) animation: Animation,
+}
+
+impl Hand {
+    pub fn new(needle: Box<dyn Needle>) -> Self {
+        Self {
+            needle,
+            color: None,
+            z: 0,
+            value: 0.0,
+            reading: Reading::InRange,
+            animation: Animation::new(Motion::Instant, 0.0, 0.0),
+        }
+    }
+
+    #[must_use]
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    #[must_use]
+    pub fn with_z(mut self, z: i32) -> Self {
+        self.z = z;
+        self
+    }
+
+    /// Current unit value, the needle may still be travelling toward it
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Range status of the last update
+    pub fn reading(&self) -> Reading {
+        self.reading
     }
+
+    /// Rest the hand at the value with the gauge motion
+    pub(crate) fn reset(&mut self, motion: Motion, value: f32, precision: f32) {
+        self.value = value;
+        self.reading = Reading::InRange;
+        self.animation = Animation::new(motion, value, precision);
+    }
+}
+
+#[test]
+fn svg_needle_points_tip_along_x() {
+    let needle = SvgNeedle::new(
+        "M50 100 L45 90 L50 30 L55 90 Z",
+        Point::new(50.0, 90.0),
+        Point::new(50.0, 30.0),
+    )
+    .unwrap();
+    let close = |a: Point, b: Point| a.distance(b) < 1.0E-4;
+    assert!(close(needle.place(needle.pivot(), 60.0), Point::ORIGIN));
+    assert!(close(
+        needle.place(needle.tip(), 60.0),
+        Point::new(60.0, 0.0)
+    ));
+    assert!(close(
+        needle.place(Point::new(50.0, 100.0), 60.0),
+        Point::new(-10.0, 0.0)
+    ));
+    let pivot = Point::new(1.0, 1.0);
+    assert_eq!(
+        SvgNeedle::new("M0 0 L2 2", pivot, pivot).err(),
+        Some(svg::Error::TipAtPivot)
+    );
 }
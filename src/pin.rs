@@ -1,6 +1,12 @@
+use std::f32::consts::FRAC_PI_3;
+
+use crate::shadow::Outline;
 use crate::style::Appearance;
 use crate::util::frame;
+use iced::widget::canvas::path::lyon_path::math::{vector, Angle, Transform};
+use iced::widget::canvas::path::Builder;
 use iced::widget::canvas::{stroke, Frame, LineCap, Path, Stroke};
+use iced::{Color, Point, Radians, Size, Vector};
 
 #[derive(Clone, Copy, Debug)]
 pub enum PinOrder {
@@ -9,13 +15,16 @@ pub enum PinOrder {
 }
 
 pub trait Pin {
-    fn path(&self, frame: &Frame, style: &Appearance) -> Path {
-        Path::circle(
-            frame.center(),
-            frame::radius(frame) * (style.pin_diameter_ratio / 4.0),
-        )
+    /// Outline of the cap
+    /// The angle is the direction of the primary needle on screen
+    fn path(&self, frame: &Frame, style: &Appearance, _angle: Radians) -> Path {
+        Path::circle(frame.center(), cap_radius(frame, style))
+    }
+    /// Shape casting the cap shadow, the filled outline unless the cap is drawn as a line
+    fn shadow(&self, frame: &Frame, style: &Appearance, angle: Radians) -> Outline {
+        Outline::Fill(self.path(frame, style, angle))
     }
-    fn stroke(&self, frame: &Frame, style: &Appearance) -> Stroke {
+    fn stroke(&self, frame: &Frame, style: &Appearance) -> Stroke<'_> {
         Stroke {
             width: frame::radius(frame) * (style.pin_border_width_ratio / 4.0),
             style: stroke::Style::Solid(style.pin_border_color),
@@ -23,24 +32,148 @@ pub trait Pin {
             ..Stroke::default()
         }
     }
-    fn draw(&self, frame: &mut Frame, style: &Appearance);
+    /// Draw the cap, the angle is the direction of the primary needle on screen
+    fn draw(&self, frame: &mut Frame, style: &Appearance, angle: Radians);
+    /// True when the cap turns with the needle and is redrawn as it moves
+    fn follows_needle(&self) -> bool {
+        false
+    }
+}
+
+fn cap_radius(frame: &Frame, style: &Appearance) -> f32 {
+    frame::radius(frame) * (style.pin_diameter_ratio / 4.0)
 }
 
 pub enum Pins {
     Solid,
     Hollow,
     Bordered,
+    /// Raised cap lit from the upper left
+    Beveled,
+    /// Highlight color brightening toward the center
+    Gradient,
+    /// Number of concentric grooves
+    Rings(u8),
+    /// Slotted screw head turning with the needle
+    Screw,
+    /// Hexagon with flats at the top and bottom around a bolt
+    HexNut,
+    /// Filled path in units of the cap radius, centered on the pivot and turning with
+    /// the needle, which points along the x axis
+    Custom(Path),
 }
 
 impl Pin for Pins {
-    fn draw(&self, frame: &mut Frame, style: &Appearance) {
+    fn shadow(&self, frame: &Frame, style: &Appearance, angle: Radians) -> Outline {
+        let path = self.path(frame, style, angle);
+        match self {
+            Pins::Hollow => Outline::Stroke(path, self.stroke(frame, style).width),
+            _ => Outline::Fill(path),
+        }
+    }
+
+    fn path(&self, frame: &Frame, style: &Appearance, angle: Radians) -> Path {
+        let center = frame.center();
+        let radius = cap_radius(frame, style);
+        match self {
+            Pins::HexNut => {
+                let mut b = Builder::new();
+                for i in 0..6 {
+                    let (sin, cos) = (i as f32 * FRAC_PI_3).sin_cos();
+                    let p = center + Vector::new(cos, sin) * radius;
+                    if i == 0 {
+                        b.move_to(p);
+                    } else {
+                        b.line_to(p);
+                    }
+                }
+                b.close();
+                b.build()
+            }
+            Pins::Custom(path) => path.transform(
+                &Transform::scale(radius, radius)
+                    .then_rotate(Angle::radians(angle.0))
+                    .then_translate(vector(center.x, center.y)),
+            ),
+            _ => Path::circle(center, radius),
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame, style: &Appearance, angle: Radians) {
+        let center = frame.center();
+        let radius = cap_radius(frame, style);
         match self {
-            Pins::Solid => frame.fill(&self.path(frame, style), style.pin_color),
-            Pins::Hollow => frame.stroke(&self.path(frame, style), self.stroke(frame, style)),
+            Pins::Solid => frame.fill(&self.path(frame, style, angle), style.pin_color),
+            Pins::Hollow => {
+                frame.stroke(&self.path(frame, style, angle), self.stroke(frame, style))
+            }
             Pins::Bordered => {
-                frame.fill(&self.path(frame, style), style.pin_color);
-                frame.stroke(&self.path(frame, style), self.stroke(frame, style));
+                frame.fill(&self.path(frame, style, angle), style.pin_color);
+                frame.stroke(&self.path(frame, style, angle), self.stroke(frame, style));
+            }
+            Pins::Beveled => {
+                frame.fill(&self.path(frame, style, angle), style.pin_color);
+                let layers = 3;
+                let color = Color {
+                    a: style.pin_highlight_color.a / layers as f32,
+                    ..style.pin_highlight_color
+                };
+                // smaller discs further toward the light brighten its side
+                for i in 1..=layers {
+                    let t = i as f32 / (layers + 1) as f32;
+                    let center = center - Vector::new(radius, radius) * (0.3 * t);
+                    frame.fill(&Path::circle(center, radius * (1.0 - 0.6 * t)), color);
+                }
+                frame.stroke(&self.path(frame, style, angle), self.stroke(frame, style));
+            }
+            Pins::Gradient => {
+                frame.fill(&self.path(frame, style, angle), style.pin_color);
+                let layers = 8;
+                let color = Color {
+                    a: style.pin_highlight_color.a / layers as f32,
+                    ..style.pin_highlight_color
+                };
+                for i in 1..layers {
+                    let t = i as f32 / layers as f32;
+                    frame.fill(&Path::circle(center, radius * (1.0 - t)), color);
+                }
             }
+            Pins::Rings(count) => {
+                frame.fill(&self.path(frame, style, angle), style.pin_color);
+                let groove = Stroke {
+                    width: radius / 12.0,
+                    style: stroke::Style::Solid(style.pin_border_color),
+                    ..Stroke::default()
+                };
+                for i in 1..=*count {
+                    let t = f32::from(i) / f32::from(*count);
+                    frame.stroke(&Path::circle(center, radius * t), groove);
+                }
+            }
+            Pins::Screw => {
+                frame.fill(&self.path(frame, style, angle), style.pin_color);
+                frame.stroke(&self.path(frame, style, angle), self.stroke(frame, style));
+                frame.with_save(|frame| {
+                    frame.translate(Vector::new(center.x, center.y));
+                    frame.rotate(angle);
+                    frame.fill_rectangle(
+                        Point::new(-0.8 * radius, -0.12 * radius),
+                        Size::new(1.6 * radius, 0.24 * radius),
+                        style.pin_border_color,
+                    );
+                });
+            }
+            Pins::HexNut => {
+                let nut = self.path(frame, style, angle);
+                frame.fill(&nut, style.pin_color);
+                frame.stroke(&nut, self.stroke(frame, style));
+                frame.fill(&Path::circle(center, 0.45 * radius), style.pin_border_color);
+            }
+            Pins::Custom(_) => frame.fill(&self.path(frame, style, angle), style.pin_color),
         }
     }
+
+    fn follows_needle(&self) -> bool {
+        matches!(self, Pins::Screw | Pins::Custom(_))
+    }
 }
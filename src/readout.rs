@@ -0,0 +1,105 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::canvas::{Frame, Text};
+use iced::{Pixels, Point, Vector};
+
+use crate::label::LabelFormatter;
+use crate::style::Appearance;
+use crate::util::frame;
+
+/// Digital display of the gauge value on the face
+pub struct Readout {
+    /// Center of the text from the gauge center, as ratios of radius
+    pub position: Vector,
+    /// Text size as ratio of radius
+    pub size_ratio: f32,
+    /// Decimal places shown
+    pub precision: usize,
+    /// Appended to the formatted value
+    pub unit: Option<String>,
+    formatter: Option<Box<dyn LabelFormatter>>,
+}
+
+impl Default for Readout {
+    fn default() -> Self {
+        Readout {
+            position: Vector::new(0.0, 0.4),
+            size_ratio: 0.15,
+            precision: 0,
+            unit: None,
+            formatter: None,
+        }
+    }
+}
+
+impl Readout {
+    #[must_use]
+    pub fn with_position(mut self, x_ratio: f32, y_ratio: f32) -> Self {
+        self.position = Vector::new(x_ratio, y_ratio);
+        self
+    }
+
+    #[must_use]
+    pub fn with_size(mut self, size_ratio: f32) -> Self {
+        self.size_ratio = size_ratio;
+        self
+    }
+
+    #[must_use]
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    #[must_use]
+    pub fn with_unit(mut self, unit: &str) -> Self {
+        self.unit = Some(unit.to_string());
+        self
+    }
+
+    /// Replace the fixed precision formatting, the unit is still appended
+    #[must_use]
+    pub fn with_formatter(mut self, formatter: impl LabelFormatter + 'static) -> Self {
+        self.formatter = Some(Box::new(formatter));
+        self
+    }
+
+    pub fn text(&self, value: f32) -> String {
+        let mut text = match &self.formatter {
+            Some(f) => f.format(value),
+            None => format!("{value:.*}", self.precision),
+        };
+        if let Some(unit) = &self.unit {
+            text.push_str(unit);
+        }
+        text
+    }
+
+    pub fn draw(&self, frame: &mut Frame, style: &Appearance, value: f32) {
+        let radius = frame::radius(frame);
+        let center = frame.center();
+        frame.fill_text(Text {
+            content: self.text(value),
+            position: Point::new(
+                center.x + self.position.x * radius,
+                center.y + self.position.y * radius,
+            ),
+            color: style.readout_color,
+            size: Pixels(self.size_ratio * radius),
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            ..Text::default()
+        });
+    }
+}
+
+#[test]
+fn text_applies_precision_and_unit() {
+    let readout = Readout::default().with_precision(1).with_unit(" psi");
+    assert_eq!(readout.text(12.345), "12.3 psi");
+    let readout = Readout::default().with_formatter(|v| format!("{:03}", v as i32));
+    assert_eq!(readout.text(7.0), "007");
+    let readout = Readout::default()
+        .with_formatter(crate::label::Fixed::new(2))
+        .with_unit(" bar");
+    assert_eq!(readout.text(1.5), "1.50 bar");
+}
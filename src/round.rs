@@ -1,18 +1,28 @@
 use std::f32::consts::TAU;
+use std::fmt::{self, Display, Formatter};
 
 use iced::alignment::Horizontal;
 use iced::mouse::Cursor;
+use iced::time::Instant;
 use iced::widget::canvas;
+use iced::widget::canvas::event;
 use iced::widget::canvas::path::arc::Elliptical;
 use iced::widget::canvas::path::Builder;
 use iced::widget::canvas::{stroke, Cache, Geometry, LineCap, Path, Program, Stroke};
-use iced::{mouse, Color, Point, Radians, Rectangle, Renderer, Theme, Vector};
+use iced::{mouse, window, Subscription};
+use iced::{Color, Point, Radians, Rectangle, Renderer, Size, Theme, Vector};
 
-use crate::needle::{Needle, Needles};
+use crate::animation::Motion;
+use crate::band::Band;
+use crate::needle::{Hand, Needle, Needles};
 use crate::pin::{Pin, PinOrder, Pins};
+use crate::readout::Readout;
+use crate::scale::{Dial, Linear};
 use crate::style::Style;
+use crate::telltale::Telltales;
+use crate::tick::{Auto, TickRing};
 use crate::util::frame;
-use crate::Tick;
+use crate::{Ellipse, Scale, Tick};
 
 pub enum Closing {
     None,
@@ -20,16 +30,41 @@ pub enum Closing {
     Sector,
 }
 
+/// Handling of values outside of the gauge range
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Overflow {
+    /// Hold the needle at the nearest stop
+    #[default]
+    Clamp,
+    /// Let the needle travel past the stops by a margin of unit values
+    Overshoot(f32),
+    /// Hold the needle at the nearest stop and mark the stop with an indicator
+    Peg,
+}
+
+/// Where an updated value fell relative to the gauge range
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Reading {
+    #[default]
+    InRange,
+    Under,
+    Over,
+    /// Not a finite number, the needle keeps its previous value
+    Invalid,
+}
+
 pub struct Gauge {
     name: Option<String>,
-    /// Current unit value
-    value: f32,
+    overflow: Overflow,
+    /// Needles on the dial, the first is the primary needle
+    hands: Vec<Hand>,
     needle_gfx: Cache,
     bg_gfx: Cache,
     border_gfx: Cache,
     ticks_gfx: Cache,
     pin_gfx: Cache,
     label_gfx: Cache,
+    readout_gfx: Cache,
     /// Radians of needle movement
     length: Radians,
     /// Radians of rotation
@@ -38,20 +73,35 @@ pub struct Gauge {
     min: f32,
     /// Unit value maximum
     max: f32,
-    /// Radians of movement per unit value
-    step: Radians,
+    /// Smallest displayable change in unit value
+    resolution: f32,
     /// Number of displayable unit steps at current resolution
     steps: usize,
     closing: Closing,
+    /// Fill the canvas as an ellipse rather than a circle
+    oval: bool,
+    pub scale: Box<dyn Scale>,
     pub ticks: Box<dyn Tick>,
-    pub needle: Box<dyn Needle>,
+    /// Further scales in other units, drawn at their own radius
+    pub rings: Vec<TickRing>,
+    /// Colored ranges drawn under the ticks
+    pub bands: Vec<Band>,
+    /// Digital display of the primary needle value
+    pub readout: Option<Readout>,
+    /// Held extremes of the primary needle value
+    pub telltales: Option<Telltales>,
     pub pin: Box<dyn Pin>,
     pub style: Style,
 }
 
 impl Gauge {
-    // length and rotate are fractions of 1.0 which is a full circle
-    // the value of these can exceed 1.0, in which case it loops
+    /// Create a gauge from positional arguments.
+    ///
+    /// length and rotate are fractions of 1.0 which is a full circle,
+    /// the value of these can exceed 1.0, in which case it loops.
+    ///
+    /// # Panics
+    /// Panics if the arguments do not describe a drawable gauge, see [`GaugeBuilder::build`].
     pub fn new(
         min: f32,
         max: f32,
@@ -61,36 +111,24 @@ impl Gauge {
         ticks: Box<dyn Tick>,
         style: Style,
     ) -> Self {
-        // wait for builder impl
-        let res = 1.0; // resolution: ie. visible values
+        Self::builder()
+            .range(min, max)
+            .length(length)
+            .rotate(rotate)
+            .closing(closing)
+            .ticks(ticks)
+            .style(style)
+            .build()
+            .expect("invalid gauge")
+    }
 
-        // derived
-        let rotate = TAU * rotate;
-        let length = TAU * length;
-        let steps = (max - min) / res;
-        let step = length / steps;
+    pub fn builder() -> GaugeBuilder {
+        GaugeBuilder::default()
+    }
 
-        Self {
-            name: None,
-            value: 0.0,
-            needle_gfx: Default::default(),
-            bg_gfx: Default::default(),
-            border_gfx: Default::default(),
-            ticks_gfx: Default::default(),
-            pin_gfx: Default::default(),
-            label_gfx: Default::default(),
-            length: Radians(length),
-            rotate: Radians(rotate),
-            min,
-            max,
-            step: Radians(step),
-            steps: steps as usize,
-            closing,
-            ticks,
-            needle: Box::new(Needles::Diamond),
-            pin: Box::new(Pins::Solid),
-            style,
-        }
+    /// Number of displayable unit steps at the configured resolution
+    pub fn steps(&self) -> usize {
+        self.steps
     }
 
     #[must_use]
@@ -101,7 +139,7 @@ impl Gauge {
 
     #[must_use]
     pub fn with_needle(mut self, needle: Box<dyn Needle>) -> Self {
-        self.needle = needle;
+        self.hands[0].needle = needle;
         self
     }
 
@@ -111,52 +149,222 @@ impl Gauge {
         self
     }
 
-    pub fn update_value(&mut self, v: f32) {
-        // todo;; what to do about constraining value by min/max?
-        if v < self.min || v > self.max {
-            println!("constratint violation: {} < {} < {}", self.min, v, self.max);
+    /// Move the primary needle to the value, constrained by the overflow policy
+    pub fn update_value(&mut self, v: f32) -> Reading {
+        self.update_needle(0, v, Instant::now())
+    }
+
+    /// Move the indexed needle to the value, constrained by the overflow policy
+    /// The instant is when the value was read, which the telltales hold extremes from
+    ///
+    /// # Panics
+    /// Panics if the gauge has no needle at the index.
+    pub fn update_needle(&mut self, index: usize, v: f32, now: Instant) -> Reading {
+        if !v.is_finite() {
+            self.hands[index].reading = Reading::Invalid;
+            return Reading::Invalid;
         }
-        self.value = v;
+        let (lo, hi) = self.travel();
+        let snapped = self.ticks.snap(v);
+        let reading = if v < self.min {
+            Reading::Under
+        } else if v > self.max {
+            Reading::Over
+        } else {
+            Reading::InRange
+        };
+        let hand = &mut self.hands[index];
+        hand.reading = reading;
+        hand.value = snapped.unwrap_or(v).clamp(lo, hi);
+        hand.animation.retarget(hand.value);
         self.needle_gfx.clear();
+        if index == 0 {
+            let value = hand.value;
+            if let Some(telltales) = self.telltales.as_mut() {
+                telltales.record(value, now);
+            }
+            self.readout_gfx.clear();
+            if self.pin.follows_needle() {
+                self.pin_gfx.clear();
+            }
+        }
+        reading
+    }
+
+    /// Restart tracking of the highest and lowest values from the current value
+    pub fn reset_extremes(&mut self, now: Instant) {
+        let value = self.value();
+        if let Some(telltales) = self.telltales.as_mut() {
+            telltales.reset();
+            telltales.record(value, now);
+            self.needle_gfx.clear();
+        }
+    }
+
+    /// Current unit value of the primary needle, which may still be travelling toward it
+    pub fn value(&self) -> f32 {
+        self.hands[0].value
+    }
+
+    /// Range status of the last primary needle update
+    pub fn reading(&self) -> Reading {
+        self.hands[0].reading
+    }
+
+    /// Needles on the dial, the first is the primary needle
+    pub fn hands(&self) -> &[Hand] {
+        &self.hands
+    }
+
+    /// Move animated needles to the instant
+    /// Returns true while any needle is still travelling
+    pub fn advance(&mut self, now: Instant) -> bool {
+        let mut changed = false;
+        for hand in &mut self.hands {
+            changed |= hand.animation.advance(now);
+        }
+        let value = self.value();
+        if let Some(telltales) = self.telltales.as_mut() {
+            changed |= telltales.record(value, now);
+        }
+        if changed {
+            self.needle_gfx.clear();
+            if self.pin.follows_needle() {
+                self.pin_gfx.clear();
+            }
+        }
+        self.is_animating()
+    }
+
+    pub fn is_animating(&self) -> bool {
+        self.hands.iter().any(|h| h.animation.is_animating())
+    }
+
+    /// Instant a held telltale extreme next decays, if one is pending
+    ///
+    /// Decay is applied when values arrive or on [`Gauge::advance`], so an application
+    /// whose values may stop arriving should drive `advance` from a timer, such as
+    /// `iced::time::every`, until this deadline passes.
+    pub fn decay_deadline(&self) -> Option<Instant> {
+        self.telltales.as_ref()?.deadline(self.value())
+    }
+
+    /// Frame instants to feed into [`Gauge::advance`] while a needle is travelling
+    /// Telltale decay is not covered, see [`Gauge::decay_deadline`]
+    pub fn subscription(&self) -> Subscription<Instant> {
+        if self.is_animating() {
+            window::frames()
+        } else {
+            Subscription::none()
+        }
+    }
+
+    /// Unit value range the needle may travel under the overflow policy
+    fn travel(&self) -> (f32, f32) {
+        match self.overflow {
+            Overflow::Clamp | Overflow::Peg => (self.min, self.max),
+            Overflow::Overshoot(margin) => (self.min - margin, self.max + margin),
+        }
+    }
+
+    /// Round the value to the gauge resolution, or the tick it snaps to, within the range
+    ///
+    /// Resolution steps are spread evenly along the dial, so on a non-linear scale they
+    /// follow the scale rather than the unit values.
+    pub fn snap(&self, v: f32) -> f32 {
+        let v = match self.ticks.snap(v) {
+            Some(v) => v,
+            None => {
+                let (min, max) = (self.min, self.max);
+                let steps = (max - min) / self.resolution;
+                let fraction = self.scale.fraction(v, min, max);
+                self.scale
+                    .value((fraction * steps).round() / steps, min, max)
+            }
+        };
+        v.clamp(self.min, self.max)
+    }
+
+    /// Unit value pointed at by an angle from the center, snapped to the resolution
+    /// Angles beyond the arc are bounded to the nearest stop
+    pub fn value_at(&self, angle: Radians) -> f32 {
+        let relative = (angle.0 - self.rotate.0).rem_euclid(TAU);
+        let relative = if relative > self.length.0 {
+            // outside the arc, pick the closer end
+            if relative - self.length.0 < TAU - relative {
+                self.length.0
+            } else {
+                0.0
+            }
+        } else {
+            relative
+        };
+        self.snap(self.dial().value(Radians(relative)))
+    }
+
+    /// Let the user press and drag on the dial to choose a value
+    ///
+    /// The message is produced with each new value, the needle moves once the
+    /// application passes that value back through [`Gauge::update_value`].
+    pub fn interactive<'a, M>(&'a self, on_change: impl Fn(f32) -> M + 'a) -> Interactive<'a, M> {
+        Interactive {
+            gauge: self,
+            on_change: Box::new(on_change),
+        }
+    }
+
+    /// The value to angle mapping shared by the needle and ticks
+    pub fn dial(&self) -> Dial<'_> {
+        Dial {
+            min: self.min,
+            max: self.max,
+            length: self.length,
+            rotate: self.rotate,
+            scale: self.scale.as_ref(),
+        }
     }
 
     pub fn repaint(&self) {
         self.needle_gfx.clear();
+        self.readout_gfx.clear();
         self.bg_gfx.clear();
         self.ticks_gfx.clear();
         self.pin_gfx.clear();
         self.border_gfx.clear();
     }
 
-    fn bg_path(&self, center: Point, radius: f32) -> Path {
+    /// Outline of the face for a canvas size, centered on the origin
+    /// An oval gauge fills the canvas, a round one fits its shorter side
+    pub fn face(&self, size: Size) -> Ellipse {
+        if self.oval {
+            Ellipse::oval(size.width / 2.0, size.height / 2.0)
+        } else {
+            Ellipse::round(size.width.min(size.height) / 2.0)
+        }
+    }
+
+    fn bg_path(&self, face: &Ellipse) -> Path {
+        let mut builder = Builder::new();
         match self.closing {
-            Closing::None => Path::circle(center, radius),
+            Closing::None => builder.ellipse(Elliptical {
+                center: face.center,
+                radii: Vector::new(1.0 / face.major_curvature, face.minor_radius),
+                rotation: Radians(0.0),
+                start_angle: Radians(0.0),
+                end_angle: Radians(TAU),
+            }),
             Closing::Sector => {
-                let mut builder = Builder::new();
-                builder.ellipse(Elliptical {
-                    center,
-                    radii: Vector::new(radius, radius),
-                    rotation: self.rotate,
-                    start_angle: Radians(0.0),
-                    end_angle: self.length,
-                });
-                builder.line_to(center);
-                builder.close();
-                builder.build()
+                let start = self.rotate.0;
+                face.trace(&mut builder, start, start + self.length.0);
+                builder.line_to(face.center);
             }
             Closing::Segment => {
-                let mut builder = Builder::new();
-                builder.ellipse(Elliptical {
-                    center,
-                    radii: Vector::new(radius, radius),
-                    rotation: self.rotate,
-                    start_angle: Radians(0.0),
-                    end_angle: self.length,
-                });
-                builder.close();
-                builder.build()
+                let start = self.rotate.0;
+                face.trace(&mut builder, start, start + self.length.0);
             }
         }
+        builder.close();
+        builder.build()
     }
 
     fn stroke<'a>(&self, width: f32, color: Color) -> Stroke<'a> {
@@ -169,46 +377,497 @@ impl Gauge {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error {
+    /// The unit value minimum must be strictly less than the maximum
+    InvalidRange { min: f32, max: f32 },
+    /// The arc length must be a positive fraction of a circle
+    InvalidLength(f32),
+    /// The resolution must be positive and leave at least one step in the range
+    InvalidResolution(f32),
+    /// A tick ring conversion must have a positive finite factor and a finite offset
+    InvalidRing { factor: f32, offset: f32 },
+    /// An overshoot margin must be finite and not negative
+    InvalidOverflow(f32),
+    /// A spring needs finite positive stiffness and damping to come to rest
+    InvalidMotion { stiffness: f32, damping: f32 },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidRange { min, max } => {
+                write!(f, "invalid range: min {min} must be less than max {max}")
+            }
+            Error::InvalidLength(l) => write!(f, "invalid length: {l} must be positive"),
+            Error::InvalidResolution(r) => {
+                write!(f, "invalid resolution: {r} leaves no steps in range")
+            }
+            Error::InvalidRing { factor, offset } => {
+                write!(
+                    f,
+                    "invalid tick ring: factor {factor} must be positive and offset {offset} finite"
+                )
+            }
+            Error::InvalidMotion { stiffness, damping } => write!(
+                f,
+                "invalid motion: spring stiffness {stiffness} and damping {damping} must be positive"
+            ),
+            Error::InvalidOverflow(m) => {
+                write!(
+                    f,
+                    "invalid overflow: overshoot margin {m} must be finite and not negative"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub struct GaugeBuilder {
+    name: Option<String>,
+    min: f32,
+    max: f32,
+    length: f32,
+    rotate: f32,
+    resolution: f32,
+    overflow: Overflow,
+    motion: Motion,
+    closing: Closing,
+    oval: bool,
+    scale: Box<dyn Scale>,
+    ticks: Option<Box<dyn Tick>>,
+    rings: Vec<TickRing>,
+    bands: Vec<Band>,
+    readout: Option<Readout>,
+    telltales: Option<Telltales>,
+    needle: Box<dyn Needle>,
+    hands: Vec<Hand>,
+    pin: Box<dyn Pin>,
+    style: Style,
+}
+
+impl Default for GaugeBuilder {
+    fn default() -> Self {
+        Self {
+            name: None,
+            min: 0.0,
+            max: 100.0,
+            length: 0.75,
+            rotate: 0.375,
+            resolution: 1.0,
+            overflow: Overflow::default(),
+            motion: Motion::default(),
+            closing: Closing::None,
+            oval: false,
+            scale: Box::new(Linear),
+            ticks: None,
+            rings: vec![],
+            bands: vec![],
+            readout: None,
+            telltales: None,
+            needle: Box::new(Needles::Diamond),
+            hands: vec![],
+            pin: Box::new(Pins::Solid),
+            style: Style::default(),
+        }
+    }
+}
+
+impl GaugeBuilder {
+    /// Unit value minimum and maximum
+    #[must_use]
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Needle movement as a fraction of a full circle
+    #[must_use]
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Rotation of the minimum as a fraction of a full circle
+    #[must_use]
+    pub fn rotate(mut self, rotate: f32) -> Self {
+        self.rotate = rotate;
+        self
+    }
+
+    /// Smallest displayable change in unit value
+    #[must_use]
+    pub fn resolution(mut self, resolution: f32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Handling of values outside of the range, clamped by default
+    #[must_use]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Needle travel between values, instant by default
+    #[must_use]
+    pub fn motion(mut self, motion: Motion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    #[must_use]
+    pub fn closing(mut self, closing: Closing) -> Self {
+        self.closing = closing;
+        self
+    }
+
+    /// Stretch the face into an ellipse filling the canvas
+    #[must_use]
+    pub fn oval(mut self, oval: bool) -> Self {
+        self.oval = oval;
+        self
+    }
+
+    /// Mapping of unit values to the arc, linear by default
+    #[must_use]
+    pub fn scale(mut self, scale: Box<dyn Scale>) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    #[must_use]
+    pub fn ticks(mut self, ticks: Box<dyn Tick>) -> Self {
+        self.ticks = Some(ticks);
+        self
+    }
+
+    /// Add a scale in another unit alongside the ticks
+    #[must_use]
+    pub fn ring(mut self, ring: TickRing) -> Self {
+        self.rings.push(ring);
+        self
+    }
+
+    /// Add a colored range under the ticks
+    #[must_use]
+    pub fn band(mut self, band: Band) -> Self {
+        self.bands.push(band);
+        self
+    }
+
+    /// Show the primary needle value as text on the face
+    #[must_use]
+    pub fn readout(mut self, readout: Readout) -> Self {
+        self.readout = Some(readout);
+        self
+    }
+
+    /// Mark the highest and lowest values since the last reset
+    #[must_use]
+    pub fn telltales(mut self, telltales: Telltales) -> Self {
+        self.telltales = Some(telltales);
+        self
+    }
+
+    #[must_use]
+    pub fn needle(mut self, needle: Box<dyn Needle>) -> Self {
+        self.needle = needle;
+        self
+    }
+
+    /// Add a needle after the primary needle, moved with [`Gauge::update_needle`]
+    #[must_use]
+    pub fn hand(mut self, hand: Hand) -> Self {
+        self.hands.push(hand);
+        self
+    }
+
+    #[must_use]
+    pub fn pin(mut self, pin: Box<dyn Pin>) -> Self {
+        self.pin = pin;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<Gauge, Error> {
+        let (min, max) = (self.min, self.max);
+        if !min.is_finite() || !max.is_finite() || min >= max || !self.scale.supports(min, max) {
+            return Err(Error::InvalidRange { min, max });
+        }
+        if !self.length.is_finite() || self.length <= 0.0 {
+            return Err(Error::InvalidLength(self.length));
+        }
+        let steps = (max - min) / self.resolution;
+        if !self.resolution.is_finite() || self.resolution <= 0.0 || steps < 1.0 {
+            return Err(Error::InvalidResolution(self.resolution));
+        }
+        if let Overflow::Overshoot(margin) = self.overflow {
+            if !margin.is_finite() || margin < 0.0 {
+                return Err(Error::InvalidOverflow(margin));
+            }
+        }
+        if let Motion::Spring { stiffness, damping } = self.motion {
+            let positive = |x: f32| x.is_finite() && x > 0.0;
+            if !positive(stiffness) || !positive(damping) {
+                return Err(Error::InvalidMotion { stiffness, damping });
+            }
+        }
+        if let Some(ring) = self
+            .rings
+            .iter()
+            .find(|r| !r.factor.is_finite() || r.factor <= 0.0 || !r.offset.is_finite())
+        {
+            return Err(Error::InvalidRing {
+                factor: ring.factor,
+                offset: ring.offset,
+            });
+        }
+
+        // derived
+        let rotate = TAU * self.rotate;
+        let length = TAU * self.length;
+
+        // settle within a tenth of the finest resolution step along the dial
+        let fine = 1.0 / (10.0 * steps);
+        let precision = (self.scale.value(fine, min, max) - min)
+            .abs()
+            .min((max - self.scale.value(1.0 - fine, min, max)).abs());
+        let mut hands = vec![Hand::new(self.needle)];
+        hands.extend(self.hands);
+        for hand in &mut hands {
+            hand.reset(self.motion, min, precision);
+        }
+
+        Ok(Gauge {
+            name: self.name,
+            overflow: self.overflow,
+            hands,
+            needle_gfx: Default::default(),
+            bg_gfx: Default::default(),
+            border_gfx: Default::default(),
+            ticks_gfx: Default::default(),
+            pin_gfx: Default::default(),
+            label_gfx: Default::default(),
+            readout_gfx: Default::default(),
+            length: Radians(length),
+            rotate: Radians(rotate),
+            min,
+            max,
+            resolution: self.resolution,
+            steps: steps as usize,
+            closing: self.closing,
+            oval: self.oval,
+            scale: self.scale,
+            ticks: self.ticks.unwrap_or_else(|| Auto::boxed(0.30)),
+            rings: self.rings,
+            bands: self.bands,
+            readout: self.readout,
+            telltales: self.telltales,
+            pin: self.pin,
+            style: self.style,
+        })
+    }
+}
+
+/// A gauge that sets its value from pointer drags, see [`Gauge::interactive`]
+pub struct Interactive<'a, M> {
+    gauge: &'a Gauge,
+    on_change: Box<dyn Fn(f32) -> M + 'a>,
+}
+
+#[derive(Default)]
+pub struct Drag {
+    dragging: bool,
+}
+
+impl<M> Interactive<'_, M> {
+    /// Value under the cursor, if it is over the dial
+    fn value_under(&self, bounds: Rectangle, cursor: Cursor) -> Option<f32> {
+        let p = cursor.position_in(bounds)?;
+        let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
+        let v = p - center;
+        if v.x == 0.0 && v.y == 0.0 {
+            return None;
+        }
+        Some(self.gauge.value_at(Radians(v.y.atan2(v.x))))
+    }
+
+    fn over_dial(&self, bounds: Rectangle, cursor: Cursor) -> bool {
+        cursor.position_in(bounds).is_some_and(|p| {
+            let v = p - Point::new(bounds.width / 2.0, bounds.height / 2.0);
+            let face = self.gauge.face(bounds.size());
+            v.x.hypot(v.y) <= face.radius_at(v.y.atan2(v.x))
+        })
+    }
+}
+
+impl<M> Program<M> for Interactive<'_, M> {
+    type State = Drag;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<M>) {
+        let canvas::Event::Mouse(event) = event else {
+            return (event::Status::Ignored, None);
+        };
+        match event {
+            mouse::Event::ButtonPressed(mouse::Button::Left) if self.over_dial(bounds, cursor) => {
+                state.dragging = true;
+            }
+            mouse::Event::CursorMoved { .. } if state.dragging => {}
+            mouse::Event::ButtonReleased(mouse::Button::Left) if state.dragging => {
+                state.dragging = false;
+                return (event::Status::Captured, None);
+            }
+            _ => return (event::Status::Ignored, None),
+        }
+        let message = self.value_under(bounds, cursor).map(&self.on_change);
+        (event::Status::Captured, message)
+    }
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        <Gauge as Program<M>>::draw(self.gauge, &(), renderer, theme, bounds, cursor)
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if state.dragging {
+            mouse::Interaction::Grabbing
+        } else if self.over_dial(bounds, cursor) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
 impl<M> Program<M> for Gauge {
     type State = ();
 
     fn draw(
         &self,
-        state: &Self::State,
+        _state: &Self::State,
         renderer: &Renderer,
         theme: &Theme,
         bounds: Rectangle,
-        cursor: Cursor,
+        _cursor: Cursor,
     ) -> Vec<Geometry> {
         let style = self.style.for_theme(theme);
+        let face = self.face(bounds.size());
+        // the outline as seen from frames rotated to the gauge minimum
+        let dial_face = face.rotated(-self.rotate.0);
+        let ring = dial_face.scaled(style.tick_border_inset_ratio);
+        let border_width = face.min_radius() / style.border_width_ratio;
+        let border_ratio = 1.0 - 1.0 / style.border_width_ratio;
 
         let bg = self.bg_gfx.draw(renderer, bounds.size(), |frame| {
-            let frame_radius = frame::radius(frame);
-            let border_radius = frame_radius - frame_radius / style.border_width_ratio;
-
-            let background = self.bg_path(frame.center(), border_radius);
+            let background = self.bg_path(&Ellipse {
+                center: frame.center(),
+                ..face.scaled(border_ratio)
+            });
             frame.fill(&background, style.background_color);
         });
 
         let border = self.border_gfx.draw(renderer, bounds.size(), |frame| {
-            let frame_radius = frame::radius(frame);
-            let border_width = frame_radius / style.border_width_ratio;
-            let border_inner_radius = frame_radius - border_width;
-
             frame.stroke(
-                &self.bg_path(frame.center(), border_inner_radius),
+                &self.bg_path(&Ellipse {
+                    center: frame.center(),
+                    ..face.scaled(border_ratio)
+                }),
                 self.stroke(border_width, style.border_color),
             );
         });
 
         let needle = self.needle_gfx.draw(renderer, bounds.size(), |frame| {
             let center = frame.center();
-            frame.with_save(|frame| {
-                frame.translate(Vector::new(center.x, center.y));
-                frame.rotate(self.rotate);
-                frame.rotate(self.value * self.step);
-                self.needle.draw(frame::radius(frame), self.value, frame);
-            });
+            let (lo, hi) = self.travel();
+            if let Some(telltales) = self.telltales.as_ref() {
+                frame.with_save(|frame| {
+                    frame.translate(Vector::new(center.x, center.y));
+                    frame.rotate(self.rotate);
+                    telltales.draw(frame, style, &self.dial(), &ring);
+                });
+            }
+
+            let mut hands: Vec<&Hand> = self.hands.iter().collect();
+            hands.sort_by_key(|h| h.z);
+
+            if let Some(shadow) = style.needle_shadow {
+                for hand in &hands {
+                    let value = hand.animation.current().clamp(lo, hi);
+                    shadow.cast(frame, face.min_radius(), |frame| {
+                        frame.translate(Vector::new(center.x, center.y));
+                        frame.rotate(self.rotate);
+                        let angle = self.dial().angle(value);
+                        frame.rotate(angle);
+                        let radius = dial_face.radius_at(angle.0);
+                        hand.needle.shadow(
+                            radius * style.needle_length_ratio,
+                            radius * style.needle_width_ratio,
+                        )
+                    });
+                }
+            }
+
+            for hand in hands {
+                let value = hand.animation.current().clamp(lo, hi);
+                frame.with_save(|frame| {
+                    frame.translate(Vector::new(center.x, center.y));
+                    frame.rotate(self.rotate);
+                    let angle = self.dial().angle(value);
+                    frame.rotate(angle);
+                    let color = hand.color.unwrap_or(style.needle_color);
+                    hand.needle
+                        .draw(dial_face.radius_at(angle.0), value, color, style, frame);
+                });
+
+                let stop = match (self.overflow, hand.reading) {
+                    (Overflow::Peg, Reading::Under) => Some(self.min),
+                    (Overflow::Peg, Reading::Over) => Some(self.max),
+                    _ => None,
+                };
+                if let Some(stop) = stop {
+                    frame.with_save(|frame| {
+                        frame.translate(Vector::new(center.x, center.y));
+                        frame.rotate(self.rotate);
+                        let p = ring.get_polar_point(self.dial().angle(stop).0);
+                        frame.fill(
+                            &Path::circle(p, ring.min_radius() / 15.0),
+                            style.overflow_indicator_color,
+                        );
+                    });
+                }
+            }
         });
 
         let ticks = self.ticks_gfx.draw(renderer, bounds.size(), |frame| {
@@ -216,13 +875,30 @@ impl<M> Program<M> for Gauge {
             frame.with_save(|frame| {
                 frame.translate(Vector::new(center.x, center.y));
                 frame.rotate(self.rotate);
-                self.ticks
-                    .draw(frame, &style, self.length, self.step, self.rotate);
+                for band in &self.bands {
+                    band.draw(frame, style, &self.dial(), &ring);
+                }
+                self.ticks.draw(frame, style, &self.dial(), &ring);
+                for tick_ring in &self.rings {
+                    let ring_style = tick_ring
+                        .style
+                        .as_ref()
+                        .map_or(style, |s| s.for_theme(theme));
+                    tick_ring.draw(frame, ring_style, &self.dial(), &dial_face);
+                }
             });
         });
 
         let pin = self.pin_gfx.draw(renderer, bounds.size(), |frame| {
-            self.pin.draw(frame, style);
+            let (lo, hi) = self.travel();
+            let value = self.hands[0].animation.current().clamp(lo, hi);
+            let angle = Radians(self.rotate.0 + self.dial().angle(value).0);
+            if let Some(shadow) = style.pin_shadow {
+                shadow.cast(frame, face.min_radius(), |frame| {
+                    self.pin.shadow(frame, style, angle)
+                });
+            }
+            self.pin.draw(frame, style, angle);
         });
 
         let label = self.label_gfx.draw(renderer, bounds.size(), |frame| {
@@ -238,9 +914,157 @@ impl<M> Program<M> for Gauge {
             }
         });
 
+        let readout = self.readout_gfx.draw(renderer, bounds.size(), |frame| {
+            if let Some(readout) = self.readout.as_ref() {
+                readout.draw(frame, style, self.value());
+            }
+        });
+
         match style.pin_style {
-            PinOrder::Over => vec![bg, ticks, border, needle, pin, label],
-            PinOrder::Under => vec![bg, ticks, border, pin, needle, label],
+            PinOrder::Over => vec![bg, ticks, border, readout, needle, pin, label],
+            PinOrder::Under => vec![bg, ticks, border, readout, pin, needle, label],
         }
     }
 }
+
+#[test]
+fn build_rejects_invalid_setup() {
+    let err = |b: GaugeBuilder| b.build().err();
+    assert_eq!(
+        err(Gauge::builder().range(10.0, 10.0)),
+        Some(Error::InvalidRange {
+            min: 10.0,
+            max: 10.0
+        })
+    );
+    assert_eq!(
+        err(Gauge::builder().length(0.0)),
+        Some(Error::InvalidLength(0.0))
+    );
+    assert_eq!(
+        err(Gauge::builder().range(0.0, 1.0).resolution(2.0)),
+        Some(Error::InvalidResolution(2.0))
+    );
+    assert_eq!(
+        err(Gauge::builder().motion(Motion::Spring {
+            stiffness: 60.0,
+            damping: -1.0
+        })),
+        Some(Error::InvalidMotion {
+            stiffness: 60.0,
+            damping: -1.0
+        })
+    );
+    assert_eq!(
+        err(Gauge::builder().overflow(Overflow::Overshoot(-6.0))),
+        Some(Error::InvalidOverflow(-6.0))
+    );
+    assert_eq!(
+        err(Gauge::builder().ring(TickRing::new(Auto::boxed(0.2), -1.0))),
+        Some(Error::InvalidRing {
+            factor: -1.0,
+            offset: 0.0
+        })
+    );
+}
+
+#[test]
+fn build_derives_steps_from_resolution() {
+    let gauge = Gauge::builder()
+        .range(0.0, 1.0)
+        .resolution(0.01)
+        .build()
+        .unwrap();
+    assert_eq!(gauge.steps(), 100);
+}
+
+#[test]
+fn update_value_applies_overflow_policy() {
+    let mut gauge = Gauge::builder().range(0.0, 10.0).build().unwrap();
+    assert_eq!(gauge.update_value(12.0), Reading::Over);
+    assert_eq!(gauge.value(), 10.0);
+
+    let mut gauge = Gauge::builder()
+        .range(0.0, 10.0)
+        .overflow(Overflow::Overshoot(1.0))
+        .build()
+        .unwrap();
+    assert_eq!(gauge.update_value(-5.0), Reading::Under);
+    assert_eq!(gauge.value(), -1.0);
+    assert_eq!(gauge.update_value(5.0), Reading::InRange);
+    assert_eq!(gauge.update_value(f32::NAN), Reading::Invalid);
+    assert_eq!(gauge.value(), 5.0);
+}
+
+#[test]
+fn update_needle_moves_only_that_hand() {
+    let mut gauge = Gauge::builder()
+        .range(0.0, 10.0)
+        .hand(Hand::new(Box::new(Needles::Basic)).with_z(-1))
+        .build()
+        .unwrap();
+    gauge.update_value(3.0);
+    gauge.update_needle(1, 7.0, Instant::now());
+    assert_eq!(gauge.value(), 3.0);
+    assert_eq!(gauge.hands()[1].value(), 7.0);
+}
+
+#[test]
+fn value_at_snaps_and_bounds_to_arc() {
+    let gauge = Gauge::builder()
+        .range(0.0, 100.0)
+        .length(0.5)
+        .rotate(0.0)
+        .resolution(5.0)
+        .build()
+        .unwrap();
+    assert_eq!(gauge.value_at(Radians(TAU * 0.26)), 50.0);
+    assert_eq!(gauge.value_at(Radians(TAU * 0.6)), 100.0);
+    assert_eq!(gauge.value_at(Radians(TAU * 0.9)), 0.0);
+}
+
+#[test]
+fn value_at_follows_a_logarithmic_scale() {
+    let gauge = Gauge::builder()
+        .range(0.001, 1000.0)
+        .scale(Box::new(crate::scale::Logarithmic))
+        .length(0.5)
+        .rotate(0.0)
+        .build()
+        .unwrap();
+    // a quarter of the arc is a quarter of the six decades
+    let v = gauge.value_at(Radians(TAU * 0.5 * 0.25));
+    assert!((v - 0.0316).abs() < 1.0E-3, "{v}");
+    assert!(gauge.value_at(Radians(TAU * 0.01)) < 0.002);
+}
+
+#[test]
+fn snapping_ticks_hold_the_needle() {
+    let ticks = crate::tick::Explicit::categories(0.3, ["OFF", "1", "2", "3"]).with_snap(true);
+    let mut gauge = Gauge::builder()
+        .range(0.0, 3.0)
+        .ticks(Box::new(ticks))
+        .build()
+        .unwrap();
+    assert_eq!(gauge.update_value(1.6), Reading::InRange);
+    assert_eq!(gauge.value(), 2.0);
+    assert_eq!(gauge.snap(0.4), 0.0);
+}
+
+#[test]
+fn telltales_decay_with_the_given_clock() {
+    use crate::telltale::Marker;
+    use iced::time::Duration;
+
+    let now = Instant::now();
+    let mut gauge = Gauge::builder()
+        .telltales(Telltales::new(Marker::Needle).with_decay(Duration::from_secs(2)))
+        .build()
+        .unwrap();
+    gauge.update_needle(0, 80.0, now);
+    gauge.update_needle(0, 30.0, now + Duration::from_secs(1));
+    assert_eq!(gauge.decay_deadline(), Some(now + Duration::from_secs(2)));
+    gauge.advance(now + Duration::from_secs(3));
+    assert_eq!(gauge.telltales.as_ref().unwrap().peak(), Some(30.0));
+    assert_eq!(gauge.decay_deadline(), None);
+}
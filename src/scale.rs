@@ -0,0 +1,219 @@
+use iced::Radians;
+
+/// Maps unit values to positions along the gauge arc
+///
+/// Positions are fractions of the gauge length, 0.0 at the minimum and 1.0 at the maximum.
+pub trait Scale {
+    /// Fraction of the gauge length at which the value sits
+    fn fraction(&self, value: f32, min: f32, max: f32) -> f32;
+
+    /// Unit value sitting at the fraction of the gauge length
+    fn value(&self, fraction: f32, min: f32, max: f32) -> f32;
+
+    /// Whether the scale can map the range
+    fn supports(&self, min: f32, max: f32) -> bool {
+        min < max
+    }
+}
+
+/// Evenly spaced unit values
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Linear;
+
+impl Scale for Linear {
+    fn fraction(&self, value: f32, min: f32, max: f32) -> f32 {
+        (value - min) / (max - min)
+    }
+
+    fn value(&self, fraction: f32, min: f32, max: f32) -> f32 {
+        min + fraction * (max - min)
+    }
+}
+
+/// Evenly spaced orders of magnitude, the range must be positive
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Logarithmic;
+
+impl Scale for Logarithmic {
+    fn fraction(&self, value: f32, min: f32, max: f32) -> f32 {
+        let value = value.max(f32::MIN_POSITIVE);
+        (value.ln() - min.ln()) / (max.ln() - min.ln())
+    }
+
+    fn value(&self, fraction: f32, min: f32, max: f32) -> f32 {
+        (min.ln() + fraction * (max.ln() - min.ln())).exp()
+    }
+
+    fn supports(&self, min: f32, max: f32) -> bool {
+        min > 0.0 && min < max
+    }
+}
+
+/// Linear segments between (unit value, fraction) breakpoints
+///
+/// Values beyond the first and last breakpoints extend the end segments.
+#[derive(Clone, Debug)]
+pub struct Piecewise {
+    points: Vec<(f32, f32)>,
+}
+
+impl Piecewise {
+    pub fn new(mut points: Vec<(f32, f32)>) -> Self {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { points }
+    }
+}
+
+fn interpolate(points: impl Iterator<Item = (f32, f32)> + Clone, x: f32) -> f32 {
+    let mut last = None;
+    for (a, b) in points.clone().zip(points.skip(1)) {
+        last = Some((a, b));
+        if x <= b.0 {
+            break;
+        }
+    }
+    match last {
+        Some((a, b)) if b.0 != a.0 => a.1 + (x - a.0) / (b.0 - a.0) * (b.1 - a.1),
+        Some((a, _)) => a.1,
+        None => x,
+    }
+}
+
+impl Scale for Piecewise {
+    fn fraction(&self, value: f32, _min: f32, _max: f32) -> f32 {
+        interpolate(self.points.iter().copied(), value)
+    }
+
+    fn value(&self, fraction: f32, _min: f32, _max: f32) -> f32 {
+        interpolate(self.points.iter().map(|&(v, f)| (f, v)), fraction)
+    }
+
+    fn supports(&self, min: f32, max: f32) -> bool {
+        self.points.len() >= 2 && min < max
+    }
+}
+
+/// User defined mapping from a pair of closures
+pub struct Custom {
+    to_fraction: Box<dyn Fn(f32, f32, f32) -> f32>,
+    to_value: Box<dyn Fn(f32, f32, f32) -> f32>,
+}
+
+impl Custom {
+    /// Closures receive (value or fraction, min, max) and must be inverses of each other
+    pub fn new(
+        to_fraction: impl Fn(f32, f32, f32) -> f32 + 'static,
+        to_value: impl Fn(f32, f32, f32) -> f32 + 'static,
+    ) -> Self {
+        Self {
+            to_fraction: Box::new(to_fraction),
+            to_value: Box::new(to_value),
+        }
+    }
+}
+
+impl Scale for Custom {
+    fn fraction(&self, value: f32, min: f32, max: f32) -> f32 {
+        (self.to_fraction)(value, min, max)
+    }
+
+    fn value(&self, fraction: f32, min: f32, max: f32) -> f32 {
+        (self.to_value)(fraction, min, max)
+    }
+}
+
+/// Another unit over the same dial, such as km/h beside mph
+/// Values in the other unit are gauge values times the factor plus the offset
+pub struct Converted<'a> {
+    pub scale: &'a dyn Scale,
+    /// Positive, the other unit increases with the gauge unit
+    pub factor: f32,
+    pub offset: f32,
+}
+
+impl Converted<'_> {
+    /// Gauge value to the other unit
+    pub fn convert(&self, v: f32) -> f32 {
+        v * self.factor + self.offset
+    }
+
+    /// Other unit back to the gauge value
+    pub fn invert(&self, v: f32) -> f32 {
+        (v - self.offset) / self.factor
+    }
+}
+
+impl Scale for Converted<'_> {
+    fn fraction(&self, v: f32, min: f32, max: f32) -> f32 {
+        self.scale
+            .fraction(self.invert(v), self.invert(min), self.invert(max))
+    }
+
+    fn value(&self, fraction: f32, min: f32, max: f32) -> f32 {
+        self.convert(
+            self.scale
+                .value(fraction, self.invert(min), self.invert(max)),
+        )
+    }
+}
+
+/// The value to angle mapping of a gauge, shared by everything drawn along the arc
+///
+/// Angles are relative to the gauge rotation, 0 at the minimum.
+#[derive(Clone, Copy)]
+pub struct Dial<'a> {
+    pub min: f32,
+    pub max: f32,
+    /// Radians of needle movement
+    pub length: Radians,
+    /// Radians of rotation
+    pub rotate: Radians,
+    pub scale: &'a dyn Scale,
+}
+
+impl Dial<'_> {
+    pub fn angle(&self, value: f32) -> Radians {
+        Radians(self.length.0 * self.scale.fraction(value, self.min, self.max))
+    }
+
+    pub fn value(&self, angle: Radians) -> f32 {
+        self.scale
+            .value(angle.0 / self.length.0, self.min, self.max)
+    }
+}
+
+#[test]
+fn linear_offsets_by_min() {
+    assert_eq!(Linear.fraction(20.0, 20.0, 120.0), 0.0);
+    assert_eq!(Linear.fraction(70.0, 20.0, 120.0), 0.5);
+    assert_eq!(Linear.value(1.0, 20.0, 120.0), 120.0);
+}
+
+#[test]
+fn logarithmic_spaces_decades_evenly() {
+    let f = Logarithmic.fraction(10.0, 1.0, 1000.0);
+    assert!((f - 1.0 / 3.0).abs() < 1.0E-6);
+    assert!((Logarithmic.value(f, 1.0, 1000.0) - 10.0).abs() < 1.0E-4);
+    assert!(!Logarithmic.supports(0.0, 1000.0));
+}
+
+#[test]
+fn piecewise_round_trips() {
+    let scale = Piecewise::new(vec![(0.0, 0.0), (10.0, 0.5), (100.0, 1.0)]);
+    assert_eq!(scale.fraction(5.0, 0.0, 100.0), 0.25);
+    assert_eq!(scale.fraction(55.0, 0.0, 100.0), 0.75);
+    assert_eq!(scale.value(0.75, 0.0, 100.0), 55.0);
+    assert_eq!(scale.fraction(110.0, 0.0, 100.0), 1.0 + 10.0 / 180.0);
+}
+
+#[test]
+fn converted_shares_the_dial() {
+    let kmh = Converted {
+        scale: &Linear,
+        factor: 1.609_344,
+        offset: 0.0,
+    };
+    let (min, max) = (kmh.convert(0.0), kmh.convert(100.0));
+    assert!((kmh.fraction(kmh.convert(25.0), min, max) - 0.25).abs() < 1.0E-6);
+    assert!((kmh.value(0.5, min, max) - 80.4672).abs() < 1.0E-3);
+}
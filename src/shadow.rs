@@ -0,0 +1,78 @@
+use iced::widget::canvas::{stroke, Frame, LineJoin, Path, Stroke};
+use iced::{Color, Vector};
+
+/// Shape casting a shadow, as it is drawn
+#[derive(Clone, Debug)]
+pub enum Outline {
+    /// Filled area
+    Fill(Path),
+    /// Line of the given width in pixels
+    Stroke(Path, f32),
+}
+
+/// Soft shadow cast onto the face by a raised layer
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Shadow {
+    pub color: Color,
+    /// Displacement on screen as ratios of radius, positive is right and down
+    pub offset: Vector,
+    /// Width of the softened edge as ratio of radius
+    pub blur_ratio: f32,
+    /// Translucent copies stacked to approximate the blur
+    pub layers: u8,
+}
+
+impl Shadow {
+    pub const DEFAULT: Shadow = Shadow {
+        color: Color::from_rgba(0.0, 0.0, 0.0, 0.35),
+        offset: Vector::new(0.02, 0.03),
+        blur_ratio: 0.02,
+        layers: 4,
+    };
+
+    /// Draw the shadow of the outline returned by the closure
+    ///
+    /// The frame is already offset when the closure runs, which may transform it further
+    /// and returns the outline casting the shadow. Radius is in pixels.
+    pub fn cast(
+        &self,
+        frame: &mut Frame,
+        radius: f32,
+        outline: impl FnOnce(&mut Frame) -> Outline,
+    ) {
+        frame.with_save(|frame| {
+            frame.translate(self.offset * radius);
+            let (path, line) = match outline(frame) {
+                Outline::Fill(path) => (path, None),
+                Outline::Stroke(path, width) => (path, Some(width)),
+            };
+            let layers = self.layers.max(1);
+            let color = Color {
+                a: self.color.a / f32::from(layers),
+                ..self.color
+            };
+            // each layer spreads less, so the overlap darkens toward the middle
+            for i in 0..layers {
+                if line.is_none() {
+                    frame.fill(&path, color);
+                }
+                let spread = 1.0 - f32::from(i) / f32::from(layers);
+                frame.stroke(
+                    &path,
+                    Stroke {
+                        width: line.unwrap_or(0.0) + 2.0 * self.blur_ratio * radius * spread,
+                        style: stroke::Style::Solid(color),
+                        line_join: LineJoin::Round,
+                        ..Stroke::default()
+                    },
+                );
+            }
+        });
+    }
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Shadow::DEFAULT
+    }
+}
@@ -1,5 +1,7 @@
+use crate::label::{LabelOrientation, LabelPlacement};
 use crate::pin::PinOrder;
-use iced::{Color, Theme};
+use crate::shadow::Shadow;
+use iced::{Color, Font, Theme};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Appearance {
@@ -13,6 +15,11 @@ pub struct Appearance {
 
     pub tick_text_color: Color,
     pub tick_labels: bool,
+    pub tick_label_orientation: LabelOrientation,
+    pub tick_label_placement: LabelPlacement,
+    pub tick_label_font: Font,
+    /// Label text size as ratio of the tick ring radius
+    pub tick_label_size_ratio: f32,
 
     pub major_tick_color: Color,
     pub minor_tick_color: Color,
@@ -23,6 +30,11 @@ pub struct Appearance {
     /// Ratio of radius
     pub minor_tick_width_ratio: f32,
 
+    /// Band colors by zone
+    pub band_normal_color: Color,
+    pub band_caution_color: Color,
+    pub band_danger_color: Color,
+
     /// Spacing of tick border from center as ratio of radius
     pub tick_border_inset_ratio: f32,
 
@@ -34,8 +46,32 @@ pub struct Appearance {
     pub pin_border_width_ratio: f32,
 
     pub pin_style: PinOrder,
+    /// Light catching the raised side of a beveled pin
+    pub pin_highlight_color: Color,
+    pub pin_shadow: Option<Shadow>,
+
+    /// Pivot to tip as ratio of radius, the tick border inset reaches the ticks
+    pub needle_length_ratio: f32,
+    /// Breadth of the widest part of the needle as ratio of radius
+    pub needle_width_ratio: f32,
+    pub needle_color: Color,
+    pub needle_outline_color: Color,
+    /// Width of needle outline as ratio of radius, 0.0 draws none
+    pub needle_outline_width_ratio: f32,
+    pub needle_shadow: Option<Shadow>,
+
+    pub readout_color: Color,
+
+    /// Held extreme markers
+    pub peak_color: Color,
+    pub valley_color: Color,
+
+    /// Marker at a stop when a pegged value is out of range
+    pub overflow_indicator_color: Color,
 }
 
+// styles are few and copied by value into the gauge, boxing would cost Copy
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Copy, Debug, Default)]
 pub enum Style {
     #[default]
@@ -77,10 +113,17 @@ pub const LIGHT_DEFAULT: Appearance = Appearance {
     border_color: Color::BLACK,
     tick_text_color: Color::BLACK,
     tick_labels: true,
+    tick_label_orientation: LabelOrientation::Upright,
+    tick_label_placement: LabelPlacement::Inside,
+    tick_label_font: Font::DEFAULT,
+    tick_label_size_ratio: 0.08,
     major_tick_color: Color::BLACK,
     minor_tick_color: Color::WHITE,
     major_tick_width_ratio: 1.0,
     minor_tick_width_ratio: 0.8,
+    band_normal_color: Color::from_rgb(0.2, 0.7, 0.3),
+    band_caution_color: Color::from_rgb(0.95, 0.75, 0.1),
+    band_danger_color: Color::from_rgb(0.85, 0.15, 0.1),
 
     pin_color: Color::WHITE,
     pin_border_color: Color::BLACK,
@@ -88,6 +131,22 @@ pub const LIGHT_DEFAULT: Appearance = Appearance {
     pin_border_width_ratio: 0.0,
 
     pin_style: PinOrder::Over,
+    pin_highlight_color: Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+    pin_shadow: None,
+
+    needle_length_ratio: 0.8,
+    needle_width_ratio: 0.1,
+    needle_color: Color::BLACK,
+    needle_outline_color: Color::WHITE,
+    needle_outline_width_ratio: 0.0,
+    needle_shadow: None,
+
+    readout_color: Color::BLACK,
+
+    peak_color: Color::from_rgb(0.85, 0.15, 0.1),
+    valley_color: Color::from_rgb(0.1, 0.35, 0.85),
+
+    overflow_indicator_color: Color::from_rgb(0.9, 0.1, 0.1),
 };
 
 pub const DARK_DEFAULT: Appearance = Appearance {
@@ -98,10 +157,17 @@ pub const DARK_DEFAULT: Appearance = Appearance {
     border_color: Color::from_rgba(246.0 / 255.0, 88.0 / 255.0, 7.0 / 255.0, 1.0),
     tick_text_color: Color::BLACK,
     tick_labels: true,
+    tick_label_orientation: LabelOrientation::Upright,
+    tick_label_placement: LabelPlacement::Inside,
+    tick_label_font: Font::DEFAULT,
+    tick_label_size_ratio: 0.08,
     major_tick_color: Color::BLACK,
     minor_tick_color: Color::from_rgba(246.0 / 255.0, 88.0 / 255.0, 7.0 / 255.0, 1.0),
     major_tick_width_ratio: 1.0,
     minor_tick_width_ratio: 0.8,
+    band_normal_color: Color::from_rgb(0.2, 0.7, 0.3),
+    band_caution_color: Color::from_rgb(0.95, 0.75, 0.1),
+    band_danger_color: Color::from_rgb(0.85, 0.15, 0.1),
 
     pin_color: Color::WHITE,
     pin_border_color: Color::BLACK,
@@ -109,4 +175,20 @@ pub const DARK_DEFAULT: Appearance = Appearance {
     pin_border_width_ratio: 0.0,
 
     pin_style: PinOrder::Over,
+    pin_highlight_color: Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+    pin_shadow: None,
+
+    needle_length_ratio: 0.8,
+    needle_width_ratio: 0.1,
+    needle_color: Color::from_rgba(246.0 / 255.0, 88.0 / 255.0, 7.0 / 255.0, 1.0),
+    needle_outline_color: Color::BLACK,
+    needle_outline_width_ratio: 0.01,
+    needle_shadow: None,
+
+    readout_color: Color::WHITE,
+
+    peak_color: Color::from_rgb(0.85, 0.15, 0.1),
+    valley_color: Color::from_rgb(0.1, 0.35, 0.85),
+
+    overflow_indicator_color: Color::from_rgb(0.9, 0.1, 0.1),
 };
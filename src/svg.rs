@@ -0,0 +1,343 @@
+//! SVG path data, as found in the `d` attribute of a `<path>` element
+use std::f32::consts::TAU;
+use std::fmt::{self, Display, Formatter};
+
+use iced::{Point, Vector};
+
+/// Absolute drawing step, arcs are flattened into lines
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Segment {
+    Move(Point),
+    Line(Point),
+    /// Control and end points
+    Quadratic(Point, Point),
+    /// Two control points and the end point
+    Cubic(Point, Point, Point),
+    Close,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The data contains no commands
+    Empty,
+    /// The first command must be a move
+    MissingMoveTo,
+    /// A character that is neither a command nor a number, or a command short of numbers
+    Unexpected { offset: usize },
+    /// A needle tip must lie a finite distance away from its pivot
+    TipAtPivot,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Empty => write!(f, "empty path data"),
+            Error::MissingMoveTo => write!(f, "path data must start with a move command"),
+            Error::Unexpected { offset } => write!(f, "unexpected path data at byte {offset}"),
+            Error::TipAtPivot => write!(f, "needle tip must lie away from the pivot"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Degrees of arc covered by each line when flattening arcs
+const ARC_STEP: f32 = 5.0;
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl Cursor<'_> {
+    fn skip_separators(&mut self) {
+        while self
+            .data
+            .get(self.offset)
+            .is_some_and(|c| c.is_ascii_whitespace() || *c == b',')
+        {
+            self.offset += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.data.get(self.offset).copied()
+    }
+
+    /// True when a number follows, repeating the previous command
+    fn at_number(&mut self) -> bool {
+        self.peek()
+            .is_some_and(|c| c.is_ascii_digit() || matches!(c, b'-' | b'+' | b'.'))
+    }
+
+    fn number(&mut self) -> Result<f32, Error> {
+        self.skip_separators();
+        let start = self.offset;
+        let digits = |cursor: &mut Self| {
+            let from = cursor.offset;
+            while cursor
+                .data
+                .get(cursor.offset)
+                .is_some_and(u8::is_ascii_digit)
+            {
+                cursor.offset += 1;
+            }
+            cursor.offset > from
+        };
+        if matches!(self.data.get(self.offset), Some(b'-' | b'+')) {
+            self.offset += 1;
+        }
+        let mut any = digits(self);
+        if self.data.get(self.offset) == Some(&b'.') {
+            self.offset += 1;
+            any |= digits(self);
+        }
+        if any && matches!(self.data.get(self.offset), Some(b'e' | b'E')) {
+            let mark = self.offset;
+            self.offset += 1;
+            if matches!(self.data.get(self.offset), Some(b'-' | b'+')) {
+                self.offset += 1;
+            }
+            if !digits(self) {
+                self.offset = mark;
+            }
+        }
+        std::str::from_utf8(&self.data[start..self.offset])
+            .ok()
+            .filter(|_| any)
+            .and_then(|s| s.parse().ok())
+            .ok_or(Error::Unexpected { offset: start })
+    }
+
+    /// Arc flags may be written without separators, such as `a1 1 0 011 1`
+    fn flag(&mut self) -> Result<bool, Error> {
+        let offset = self.offset;
+        match self.peek() {
+            Some(b'0') => {
+                self.offset += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.offset += 1;
+                Ok(true)
+            }
+            _ => Err(Error::Unexpected { offset }),
+        }
+    }
+
+    fn point(&mut self, relative_to: Option<Point>) -> Result<Point, Error> {
+        let p = Point::new(self.number()?, self.number()?);
+        Ok(match relative_to {
+            Some(origin) => origin + Vector::new(p.x, p.y),
+            None => p,
+        })
+    }
+}
+
+/// Parse path data into absolute segments
+pub fn parse(d: &str) -> Result<Vec<Segment>, Error> {
+    let mut cursor = Cursor {
+        data: d.as_bytes(),
+        offset: 0,
+    };
+    let mut segments = Vec::new();
+    let mut current = Point::ORIGIN;
+    let mut start = Point::ORIGIN;
+    // reflected for the smooth curve commands
+    let mut last_control: Option<(u8, Point)> = None;
+
+    while let Some(c) = cursor.peek() {
+        let offset = cursor.offset;
+        if !c.is_ascii_alphabetic() {
+            return Err(Error::Unexpected { offset });
+        }
+        cursor.offset += 1;
+        let command = c.to_ascii_uppercase();
+        if segments.is_empty() && command != b'M' {
+            return Err(Error::MissingMoveTo);
+        }
+        let relative = c.is_ascii_lowercase();
+
+        let mut first = true;
+        while first || (command != b'Z' && cursor.at_number()) {
+            let base = relative.then_some(current);
+            let mut control = None;
+            match command {
+                b'M' if first => {
+                    current = cursor.point(base)?;
+                    start = current;
+                    segments.push(Segment::Move(current));
+                }
+                // further pairs after a move are lines
+                b'M' | b'L' => {
+                    current = cursor.point(base)?;
+                    segments.push(Segment::Line(current));
+                }
+                b'H' => {
+                    let x = cursor.number()?;
+                    current.x = if relative { current.x + x } else { x };
+                    segments.push(Segment::Line(current));
+                }
+                b'V' => {
+                    let y = cursor.number()?;
+                    current.y = if relative { current.y + y } else { y };
+                    segments.push(Segment::Line(current));
+                }
+                b'C' | b'S' => {
+                    let c1 = match (command, last_control) {
+                        (b'C', _) => cursor.point(base)?,
+                        (_, Some((b'C', p))) => reflect(p, current),
+                        _ => current,
+                    };
+                    let c2 = cursor.point(base)?;
+                    let end = cursor.point(base)?;
+                    segments.push(Segment::Cubic(c1, c2, end));
+                    control = Some((b'C', c2));
+                    current = end;
+                }
+                b'Q' | b'T' => {
+                    let c1 = match (command, last_control) {
+                        (b'Q', _) => cursor.point(base)?,
+                        (_, Some((b'Q', p))) => reflect(p, current),
+                        _ => current,
+                    };
+                    let end = cursor.point(base)?;
+                    segments.push(Segment::Quadratic(c1, end));
+                    control = Some((b'Q', c1));
+                    current = end;
+                }
+                b'A' => {
+                    let radii = Vector::new(cursor.number()?.abs(), cursor.number()?.abs());
+                    let rotation = cursor.number()?.to_radians();
+                    let large = cursor.flag()?;
+                    let sweep = cursor.flag()?;
+                    let end = cursor.point(base)?;
+                    arc(&mut segments, current, end, radii, rotation, large, sweep);
+                    current = end;
+                }
+                b'Z' => {
+                    segments.push(Segment::Close);
+                    current = start;
+                }
+                _ => return Err(Error::Unexpected { offset }),
+            }
+            last_control = control;
+            first = false;
+        }
+    }
+
+    if segments.is_empty() {
+        Err(Error::Empty)
+    } else {
+        Ok(segments)
+    }
+}
+
+fn reflect(control: Point, about: Point) -> Point {
+    about + (about - control)
+}
+
+/// Flatten an endpoint arc into lines, following the SVG implementation notes
+fn arc(
+    segments: &mut Vec<Segment>,
+    from: Point,
+    to: Point,
+    radii: Vector,
+    rotation: f32,
+    large: bool,
+    sweep: bool,
+) {
+    let (mut rx, mut ry) = (radii.x, radii.y);
+    if rx == 0.0 || ry == 0.0 || from == to {
+        segments.push(Segment::Line(to));
+        return;
+    }
+    let (sin, cos) = rotation.sin_cos();
+    let half = (from - to) * 0.5;
+    let x1 = cos * half.x + sin * half.y;
+    let y1 = -sin * half.x + cos * half.y;
+
+    // grow radii too small to reach the end point
+    let scale = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if scale > 1.0 {
+        rx *= scale.sqrt();
+        ry *= scale.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let mut k = (numerator / denominator).max(0.0).sqrt();
+    if large == sweep {
+        k = -k;
+    }
+    let cx1 = k * rx * y1 / ry;
+    let cy1 = -k * ry * x1 / rx;
+    let mid = Point::new((from.x + to.x) / 2.0, (from.y + to.y) / 2.0);
+    let center = Point::new(cos * cx1 - sin * cy1 + mid.x, sin * cx1 + cos * cy1 + mid.y);
+
+    let start = ((y1 - cy1) / ry).atan2((x1 - cx1) / rx);
+    let end = ((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx);
+    let mut delta = (end - start).rem_euclid(TAU);
+    if !sweep && delta > 0.0 {
+        delta -= TAU;
+    }
+
+    let steps = (delta.abs().to_degrees() / ARC_STEP).ceil().max(1.0) as usize;
+    for i in 1..steps {
+        let t = start + delta * i as f32 / steps as f32;
+        let (x, y) = (rx * t.cos(), ry * t.sin());
+        segments.push(Segment::Line(Point::new(
+            center.x + cos * x - sin * y,
+            center.y + sin * x + cos * y,
+        )));
+    }
+    segments.push(Segment::Line(to));
+}
+
+#[test]
+fn parses_absolute_and_relative_commands() {
+    let segments = parse("M10,20 l5-5 H30 v10 z").unwrap();
+    assert_eq!(
+        segments,
+        vec![
+            Segment::Move(Point::new(10.0, 20.0)),
+            Segment::Line(Point::new(15.0, 15.0)),
+            Segment::Line(Point::new(30.0, 15.0)),
+            Segment::Line(Point::new(30.0, 25.0)),
+            Segment::Close,
+        ]
+    );
+    // implicit lines after a move and packed numbers
+    let segments = parse("m1 1 2 2.5.5.5").unwrap();
+    assert_eq!(segments[1], Segment::Line(Point::new(3.0, 3.5)));
+    assert_eq!(segments[2], Segment::Line(Point::new(3.5, 4.0)));
+}
+
+#[test]
+fn parses_curves_and_arcs() {
+    let segments = parse("M0 0C0 1 1 1 1 0S2-1 2 0").unwrap();
+    assert_eq!(
+        segments[2],
+        Segment::Cubic(
+            Point::new(1.0, -1.0),
+            Point::new(2.0, -1.0),
+            Point::new(2.0, 0.0)
+        )
+    );
+    let segments = parse("M0 0a1 1 0 011 1").unwrap();
+    assert_eq!(segments.last(), Some(&Segment::Line(Point::new(1.0, 1.0))));
+    // a quarter circle around (0, 1) bulges toward (1, 0)
+    let Segment::Line(p) = segments[segments.len() / 2] else {
+        panic!("arcs flatten to lines")
+    };
+    assert!(p.x > 0.5 && p.y < 0.5);
+}
+
+#[test]
+fn rejects_invalid_data() {
+    assert_eq!(parse(" "), Err(Error::Empty));
+    assert_eq!(parse("L1 1"), Err(Error::MissingMoveTo));
+    assert_eq!(parse("M1 1 L2"), Err(Error::Unexpected { offset: 7 }));
+    assert_eq!(parse("M1 1 #"), Err(Error::Unexpected { offset: 5 }));
+}
@@ -0,0 +1,162 @@
+use iced::time::{Duration, Instant};
+use iced::widget::canvas::path::Builder;
+use iced::widget::canvas::{stroke, Frame, LineCap, Path, Stroke};
+
+use crate::scale::Dial;
+use crate::style::Appearance;
+use crate::Ellipse;
+
+/// How the held extremes are drawn
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Marker {
+    /// Thin secondary needle reaching the tick ring
+    #[default]
+    Needle,
+    /// Small wedge outside the tick ring pointing inward
+    Notch,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Hold {
+    value: f32,
+    at: Instant,
+}
+
+/// Highest and lowest values since the last reset
+#[derive(Clone, Debug, Default)]
+pub struct Telltales {
+    pub marker: Marker,
+    /// Extremes held longer than this fall back to the current value
+    pub decay: Option<Duration>,
+    peak: Option<Hold>,
+    valley: Option<Hold>,
+}
+
+impl Telltales {
+    pub fn new(marker: Marker) -> Self {
+        Telltales {
+            marker,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_decay(mut self, decay: Duration) -> Self {
+        self.decay = Some(decay);
+        self
+    }
+
+    /// Track the value, returns true if either extreme moved
+    pub fn record(&mut self, value: f32, now: Instant) -> bool {
+        let decay = self.decay;
+        let update = |hold: &mut Option<Hold>, beyond: fn(f32, f32) -> bool| match hold {
+            Some(h)
+                if !beyond(value, h.value)
+                    && decay.is_none_or(|d| now.saturating_duration_since(h.at) < d) =>
+            {
+                false
+            }
+            _ => {
+                let moved = hold.is_none_or(|h| h.value != value);
+                *hold = Some(Hold { value, at: now });
+                moved
+            }
+        };
+        let peak = update(&mut self.peak, |v, held| v > held);
+        let valley = update(&mut self.valley, |v, held| v < held);
+        peak || valley
+    }
+
+    /// Instant the next held extreme away from the current value decays, if any
+    pub fn deadline(&self, value: f32) -> Option<Instant> {
+        let decay = self.decay?;
+        [self.peak, self.valley]
+            .into_iter()
+            .flatten()
+            .filter(|h| h.value != value)
+            .map(|h| h.at + decay)
+            .min()
+    }
+
+    /// Forget the extremes, the next value recorded becomes both
+    pub fn reset(&mut self) {
+        self.peak = None;
+        self.valley = None;
+    }
+
+    pub fn peak(&self) -> Option<f32> {
+        self.peak.map(|h| h.value)
+    }
+
+    pub fn valley(&self) -> Option<f32> {
+        self.valley.map(|h| h.value)
+    }
+
+    /// Draw the extremes onto a frame centered and rotated to the gauge minimum
+    /// The ring is the tick border in pixels
+    pub fn draw(&self, frame: &mut Frame, style: &Appearance, dial: &Dial, ring: &Ellipse) {
+        let extremes = [
+            (self.peak(), style.peak_color),
+            (self.valley(), style.valley_color),
+        ];
+        for (value, color) in extremes {
+            let Some(value) = value else { continue };
+            let angle = dial.angle(value).0;
+            match self.marker {
+                Marker::Needle => {
+                    let path = Path::line(
+                        ring.scaled(0.2).get_polar_point(angle),
+                        ring.get_polar_point(angle),
+                    );
+                    frame.stroke(
+                        &path,
+                        Stroke {
+                            width: (ring.min_radius() / 100.0).max(1.0),
+                            style: stroke::Style::Solid(color),
+                            line_cap: LineCap::Round,
+                            ..Stroke::default()
+                        },
+                    );
+                }
+                Marker::Notch => frame.fill(&notch(ring, angle), color),
+            }
+        }
+    }
+}
+
+fn notch(ring: &Ellipse, angle: f32) -> Path {
+    let spread = 0.04;
+    let bezel = ring.scaled(1.1);
+    let mut b = Builder::new();
+    b.move_to(ring.get_polar_point(angle));
+    b.line_to(bezel.get_polar_point(angle - spread));
+    b.line_to(bezel.get_polar_point(angle + spread));
+    b.close();
+    b.build()
+}
+
+#[test]
+fn holds_extremes_until_reset() {
+    let now = Instant::now();
+    let mut t = Telltales::default();
+    for v in [5.0, 9.0, 2.0, 6.0] {
+        t.record(v, now);
+    }
+    assert_eq!((t.valley(), t.peak()), (Some(2.0), Some(9.0)));
+    t.reset();
+    t.record(6.0, now);
+    assert_eq!((t.valley(), t.peak()), (Some(6.0), Some(6.0)));
+}
+
+#[test]
+fn extremes_decay_to_current_value() {
+    let now = Instant::now();
+    let mut t = Telltales::default().with_decay(Duration::from_secs(2));
+    t.record(9.0, now);
+    t.record(4.0, now + Duration::from_secs(1));
+    assert_eq!(t.peak(), Some(9.0));
+    assert_eq!(t.deadline(4.0), Some(now + Duration::from_secs(2)));
+    assert!(t.record(4.0, now + Duration::from_secs(3)));
+    assert_eq!(t.peak(), Some(4.0));
+    assert_eq!(t.deadline(4.0), None);
+}
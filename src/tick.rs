@@ -1,51 +1,233 @@
-use iced::widget::canvas::path::arc::Elliptical;
+use std::fmt::{self, Debug, Formatter};
+use std::rc::Rc;
+
+use iced::alignment::{Horizontal, Vertical};
 use iced::widget::canvas::path::Builder;
-use iced::widget::canvas::{stroke, Frame, LineCap, Path, Stroke, Text};
-use iced::{Color, Point, Radians, Vector};
+use iced::widget::canvas::{stroke, Frame, LineCap, Stroke, Text};
+use iced::{Color, Pixels, Vector};
 
-use crate::style::Appearance;
-use crate::util::frame;
+use crate::label::LabelFormatter;
+use crate::mark::{Marks, TickMark};
+use crate::scale::{Converted, Dial};
+use crate::style::{Appearance, Style};
 use crate::Ellipse;
 
 pub trait Tick {
     /// Draw the ticks onto the frame
-    /// The gauge radius is defined in pixels
-    /// The gauge length defines the radians of needle movement
-    /// The step length defines the radians of movement per unit value
-    fn draw(
-        &self,
-        frame: &mut Frame,
-        style: &Appearance,
-        gauge_length: Radians,
-        step_length: Radians,
-        base: Radians,
-    );
+    /// The frame is centered and rotated to the gauge minimum
+    /// The dial maps unit values to radians of needle movement
+    /// The ring is the tick border, in pixels, that ticks extend inward from
+    fn draw(&self, frame: &mut Frame, style: &Appearance, dial: &Dial, ring: &Ellipse);
+
+    /// Value the needle should rest at instead of the given one, None leaves it as is
+    fn snap(&self, _value: f32) -> Option<f32> {
+        None
+    }
+}
+
+/// Tick lengths, shapes and label text shared by the tick generators
+#[derive(Clone)]
+pub struct Marking {
+    /// length of stroke, as ratio of radius 0.0 -- 1.0
+    pub major_length: f32,
+    pub minor_length: f32,
+    /// Major tick label text, the generator's own labels when not set
+    pub formatter: Option<Rc<dyn LabelFormatter>>,
+    /// Shape of the major and minor ticks
+    pub major_mark: Rc<dyn TickMark>,
+    pub minor_mark: Rc<dyn TickMark>,
+    /// Shapes replacing the major or minor mark at particular unit values
+    pub marks_at: Vec<(f32, Rc<dyn TickMark>)>,
+}
+
+impl Marking {
+    pub fn new(major_length: f32, minor_length: f32) -> Self {
+        Marking {
+            major_length,
+            minor_length,
+            formatter: None,
+            major_mark: Rc::new(Marks::Line),
+            minor_mark: Rc::new(Marks::Line),
+            marks_at: Vec::new(),
+        }
+    }
+
+    /// Formatted label, None when no formatter is set
+    fn label(&self, value: f32) -> Option<String> {
+        self.formatter.as_ref().map(|f| f.format(value))
+    }
+
+    /// Mark for the tick at the value, one listed within the tolerance comes first
+    fn mark(&self, value: f32, is_major: bool, tolerance: f32) -> &dyn TickMark {
+        match self
+            .marks_at
+            .iter()
+            .find(|(v, _)| (v - value).abs() <= tolerance)
+        {
+            Some((_, mark)) => mark.as_ref(),
+            None if is_major => self.major_mark.as_ref(),
+            None => self.minor_mark.as_ref(),
+        }
+    }
+}
+
+/// Formatter and marks are opaque, the values marked with their own shape are listed
+impl Debug for Marking {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let opaque = format_args!("..");
+        let marked: Vec<f32> = self.marks_at.iter().map(|(v, _)| *v).collect();
+        f.debug_struct("Marking")
+            .field("major_length", &self.major_length)
+            .field("minor_length", &self.minor_length)
+            .field("formatter", &self.formatter.as_ref().map(|_| opaque))
+            .field("major_mark", &opaque)
+            .field("minor_mark", &opaque)
+            .field("marks_at", &marked)
+            .finish()
+    }
 }
 
-#[derive(Copy, Clone, Debug)]
+/// Builder methods for the tick generators that embed a [`Marking`]
+pub trait Marked: Sized {
+    fn marking_mut(&mut self) -> &mut Marking;
+
+    #[must_use]
+    fn with_lengths(mut self, major: f32, minor: f32) -> Self {
+        let marking = self.marking_mut();
+        marking.major_length = major;
+        marking.minor_length = minor;
+        self
+    }
+
+    #[must_use]
+    fn with_formatter(mut self, formatter: impl LabelFormatter + 'static) -> Self {
+        self.marking_mut().formatter = Some(Rc::new(formatter));
+        self
+    }
+
+    #[must_use]
+    fn with_marks(
+        mut self,
+        major: impl TickMark + 'static,
+        minor: impl TickMark + 'static,
+    ) -> Self {
+        let marking = self.marking_mut();
+        marking.major_mark = Rc::new(major);
+        marking.minor_mark = Rc::new(minor);
+        self
+    }
+
+    /// Draw the tick at the unit value with its own mark
+    #[must_use]
+    fn with_mark_at(mut self, value: f32, mark: impl TickMark + 'static) -> Self {
+        self.marking_mut().marks_at.push((value, Rc::new(mark)));
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct MajorMinor {
-    /// radian position of the first tick
+    /// unit value the tick steps are counted from
     pub first: f32,
     /// the number of units of measure (uom) between ticks
     pub minor_step: f32,
     pub major_step: f32,
-    /// length of stroke, as ratio of radius 0.0 -- 1.0
-    pub major_length: f32,
-    pub minor_length: f32,
+    /// Labels show the plain value when no formatter is set
+    pub marking: Marking,
 }
 
 impl MajorMinor {
-    pub fn boxed(first: f32, major_step: f32, minor_step: f32, length: f32) -> Box<Self> {
-        Box::new(MajorMinor {
+    pub fn new(first: f32, major_step: f32, minor_step: f32, length: f32) -> Self {
+        MajorMinor {
             first,
             major_step,
             minor_step,
-            major_length: length,
-            minor_length: length * 0.75,
+            marking: Marking::new(length, length * 0.75),
+        }
+    }
+
+    pub fn boxed(first: f32, major_step: f32, minor_step: f32, length: f32) -> Box<Self> {
+        Box::new(Self::new(first, major_step, minor_step, length))
+    }
+
+    fn label(&self, value: f32, major_step: f32) -> String {
+        self.marking
+            .label(value)
+            .unwrap_or_else(|| format!("{value:.*}", decimals(major_step)))
+    }
+
+    /// Tick values within the range, with whether each is a major tick
+    ///
+    /// Ticks fall on multiples of the steps counted from the first tick. The major and
+    /// minor grids are each computed from integer indexes, so only visible ticks are
+    /// visited and nothing accumulates, then merged in order with a minor tick dropped
+    /// where it coincides with a major one.
+    pub fn positions(&self, min: f32, max: f32) -> impl Iterator<Item = (f32, bool)> {
+        self.grid(self.major_step, self.minor_step, min, max)
+    }
+
+    /// Tick values within the range for the given steps in place of the set ones
+    fn grid(
+        &self,
+        major_step: f32,
+        minor_step: f32,
+        min: f32,
+        max: f32,
+    ) -> impl Iterator<Item = (f32, bool)> {
+        let first = f64::from(self.first);
+        let (min, max) = (f64::from(min), f64::from(max));
+        let grid = move |step: f32| {
+            let step = f64::from(step);
+            let tolerance = 1.0E-3;
+            let (low, high) = if step > 0.0 && step.is_finite() {
+                (
+                    ((min - first) / step - tolerance).ceil() as i64,
+                    ((max - first) / step + tolerance).floor() as i64,
+                )
+            } else {
+                (1, 0)
+            };
+            (low..=high)
+                .map(move |i| first + i as f64 * step)
+                .peekable()
+        };
+        let mut majors = grid(major_step);
+        let mut minors = grid(minor_step);
+        let same = f64::from(minor_step.min(major_step).abs()) * 1.0E-3;
+
+        std::iter::from_fn(move || loop {
+            match (majors.peek().copied(), minors.peek().copied()) {
+                (Some(major), Some(minor)) if (minor - major).abs() <= same => {
+                    minors.next();
+                }
+                (Some(major), Some(minor)) if minor < major => {
+                    minors.next();
+                    return Some((minor as f32, false));
+                }
+                (Some(major), _) => {
+                    majors.next();
+                    return Some((major as f32, true));
+                }
+                (None, Some(minor)) => {
+                    minors.next();
+                    return Some((minor as f32, false));
+                }
+                (None, None) => return None,
+            }
         })
     }
 }
 
+/// Decimal places needed to show multiples of the step
+fn decimals(step: f32) -> usize {
+    (0..6)
+        .find(|&d| {
+            let scaled = step * 10f32.powi(d as i32);
+            (scaled - scaled.round()).abs() < 1.0E-3
+        })
+        .unwrap_or(6)
+}
+
 fn stroke<'a>(width: f32, color: Color) -> Stroke<'a> {
     Stroke {
         width,
@@ -55,92 +237,512 @@ fn stroke<'a>(width: f32, color: Color) -> Stroke<'a> {
     }
 }
 
-impl Tick for MajorMinor {
-    fn draw(
+/// Tick marks, labels and ring border shared by the tick generators
+struct Painter<'a> {
+    style: &'a Appearance,
+    dial: &'a Dial<'a>,
+    ring: &'a Ellipse,
+    major: Ellipse,
+    minor: Ellipse,
+    labels: Ellipse,
+    label_size: f32,
+    width_ratio: f32,
+}
+
+impl<'a> Painter<'a> {
+    fn new(
+        style: &'a Appearance,
+        dial: &'a Dial<'a>,
+        ring: &'a Ellipse,
+        marking: &Marking,
+    ) -> Self {
+        let (major_length, minor_length) = (marking.major_length, marking.minor_length);
+        Painter {
+            style,
+            dial,
+            ring,
+            major: ring.scaled(1.0 - major_length),
+            minor: ring.scaled(1.0 - minor_length),
+            labels: ring.scaled(
+                style
+                    .tick_label_placement
+                    .anchor_ratio(major_length, style.tick_label_size_ratio),
+            ),
+            label_size: ring.min_radius() * style.tick_label_size_ratio,
+            width_ratio: ring.min_radius() / 100.0,
+        }
+    }
+
+    fn major(&self, frame: &mut Frame, value: f32, mark: &dyn TickMark, label: String) {
+        let style = self.style;
+        let angle = self.dial.angle(value);
+        mark.draw(
+            frame,
+            self.major.get_polar_point(angle.0),
+            self.ring.get_polar_point(angle.0),
+            self.width_ratio * style.major_tick_width_ratio,
+            style.major_tick_color,
+        );
+
+        frame.with_save(|frame| {
+            if style.tick_labels {
+                let anchor = self.labels.get_polar_point(angle.0);
+                frame.translate(Vector::new(anchor.x, anchor.y));
+                frame.rotate(
+                    style
+                        .tick_label_orientation
+                        .rotation(angle.0, self.dial.rotate.0),
+                );
+                frame.fill_text(Text {
+                    content: label,
+                    color: style.tick_text_color,
+                    size: Pixels(self.label_size),
+                    font: style.tick_label_font,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    ..Text::default()
+                });
+            }
+        });
+    }
+
+    fn minor(&self, frame: &mut Frame, value: f32, mark: &dyn TickMark) {
+        let angle = self.dial.angle(value);
+        mark.draw(
+            frame,
+            self.minor.get_polar_point(angle.0),
+            self.ring.get_polar_point(angle.0),
+            self.width_ratio * self.style.minor_tick_width_ratio,
+            self.style.minor_tick_color,
+        );
+    }
+
+    fn border(&self, frame: &mut Frame) {
+        let mut builder = Builder::new();
+        self.ring.trace(&mut builder, 0.0, self.dial.length.0);
+        frame.stroke(
+            &builder.build(),
+            stroke(
+                self.width_ratio * self.style.tick_border_width_ratio,
+                Color::BLACK,
+            ),
+        );
+    }
+}
+
+impl Marked for MajorMinor {
+    fn marking_mut(&mut self) -> &mut Marking {
+        &mut self.marking
+    }
+}
+
+impl MajorMinor {
+    /// Draw the ticks at the given steps in place of the set ones
+    fn draw_steps(
         &self,
+        major_step: f32,
+        minor_step: f32,
         frame: &mut Frame,
         style: &Appearance,
-        size: Radians,
-        step: Radians,
-        base: Radians,
+        dial: &Dial,
+        ring: &Ellipse,
     ) {
-        let mut i = self.first;
-        let radius = frame::radius(frame) * style.tick_border_inset_ratio;
-
-        let major = Ellipse::round(radius - radius * self.major_length);
-        let minor = Ellipse::round(radius - radius * self.minor_length);
-        let outer = Ellipse::round(radius);
-
-        let width_ratio = radius / 100.0;
-
-        loop {
-            match (i % self.major_step == 0.0, i % self.minor_step == 0.0) {
-                (true, _) => {
-                    let angle = i * step;
-                    let p1 = major.get_point(angle.0);
-                    let p2 = outer.get_point(angle.0);
-                    let path = Path::line(p1, p2);
-
-                    frame.with_save(|frame| {
-                        frame.stroke(
-                            &path,
-                            stroke(
-                                width_ratio * style.major_tick_width_ratio,
-                                style.major_tick_color,
-                            ),
-                        );
-                        frame.translate(Vector::new(p1.x, p1.y));
-                        if style.tick_labels {
-                            frame.rotate(6.2 - base.0);
-                            frame.fill_text(Text {
-                                content: i.to_string(),
-                                color: style.tick_text_color,
-                                ..Text::default()
-                            });
-                        }
-                    });
-                }
-                (_, true) => {
-                    let angle = i * step;
-                    let p1 = minor.get_point(angle.0);
-                    let p2 = outer.get_point(angle.0);
-                    let path = Path::line(p1, p2);
-
-                    frame.with_save(|frame| {
-                        frame.stroke(
-                            &path,
-                            stroke(
-                                width_ratio * style.minor_tick_width_ratio,
-                                style.minor_tick_color,
-                            ),
-                        );
-                        frame.translate(Vector::new(p1.x, p1.y));
-                    });
-                }
-                _ => {}
+        let painter = Painter::new(style, dial, ring, &self.marking);
+        let tolerance = minor_step.min(major_step).abs() * 1.0E-3;
+        for (value, is_major) in self.grid(major_step, minor_step, dial.min, dial.max) {
+            let mark = self.marking.mark(value, is_major, tolerance);
+            if is_major {
+                painter.major(frame, value, mark, self.label(value, major_step));
+            } else {
+                painter.minor(frame, value, mark);
             }
+        }
+        painter.border(frame);
+    }
+}
+
+impl Tick for MajorMinor {
+    fn draw(&self, frame: &mut Frame, style: &Appearance, dial: &Dial, ring: &Ellipse) {
+        self.draw_steps(self.major_step, self.minor_step, frame, style, dial, ring);
+    }
+}
+
+/// Ticks for logarithmic scales, majors at each power of ten and minors at its 2 -- 9 multiples
+#[derive(Clone, Debug)]
+pub struct Logarithmic {
+    /// Labels show powers of ten such as 10⁻³ when no formatter is set
+    pub marking: Marking,
+}
+
+impl Logarithmic {
+    pub fn new(length: f32) -> Self {
+        Logarithmic {
+            marking: Marking::new(length, length * 0.5),
+        }
+    }
+
+    pub fn boxed(length: f32) -> Box<Self> {
+        Box::new(Self::new(length))
+    }
+
+    fn label(&self, value: f32) -> String {
+        self.marking
+            .label(value)
+            .unwrap_or_else(|| power_of_ten(value.log10().round() as i32))
+    }
+
+    /// Tick values within the positive range, with whether each is a power of ten
+    pub fn positions(&self, min: f32, max: f32) -> impl Iterator<Item = (f32, bool)> {
+        let min = min.max(f32::MIN_POSITIVE);
+        let tolerance = 1.0E-4;
+        let (low, high) = (min.log10().floor() as i32, max.log10().ceil() as i32);
+        (low..=high)
+            .flat_map(|e| (1..10).map(move |m| (m as f64 * 10f64.powi(e), m == 1)))
+            .map(|(v, major)| (v as f32, major))
+            .filter(move |(v, _)| *v >= min * (1.0 - tolerance) && *v <= max * (1.0 + tolerance))
+    }
+}
+
+/// 10 raised to the exponent in superscript digits
+fn power_of_ten(exponent: i32) -> String {
+    const SUPERSCRIPT: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    let mut text = String::from("10");
+    if exponent < 0 {
+        text.push('⁻');
+    }
+    for digit in exponent.unsigned_abs().to_string().bytes() {
+        text.push(SUPERSCRIPT[(digit - b'0') as usize]);
+    }
+    text
+}
 
-            if i * step >= size {
-                break;
+impl Marked for Logarithmic {
+    fn marking_mut(&mut self) -> &mut Marking {
+        &mut self.marking
+    }
+}
+
+impl Tick for Logarithmic {
+    fn draw(&self, frame: &mut Frame, style: &Appearance, dial: &Dial, ring: &Ellipse) {
+        let painter = Painter::new(style, dial, ring, &self.marking);
+        for (value, is_major) in self.positions(dial.min, dial.max) {
+            // ticks grow tenfold each decade, so values are matched relative to their size
+            let mark = self.marking.mark(value, is_major, value * 1.0E-3);
+            if is_major {
+                painter.major(frame, value, mark, self.label(value));
+            } else {
+                painter.minor(frame, value, mark);
             }
+        }
+        painter.border(frame);
+    }
+}
 
-            i += 1.0;
+/// Ticks at readable 1, 2 or 5 × 10ⁿ intervals chosen to suit the range and gauge size
+#[derive(Clone, Debug)]
+pub struct Auto {
+    /// Preferred arc distance between major ticks, in pixels
+    pub spacing: f32,
+    /// Ticks drawn with the chosen steps in place of their own
+    pub ticks: MajorMinor,
+}
+
+impl Auto {
+    pub fn new(length: f32) -> Self {
+        Auto {
+            spacing: 60.0,
+            ticks: MajorMinor::new(0.0, 1.0, 1.0, length),
         }
+    }
 
-        frame.with_save(|frame| {
-            let mut builder = Builder::new();
-            builder.ellipse(Elliptical {
-                center: Point::ORIGIN,
-                radii: Vector::new(radius, radius),
-                rotation: Radians(0.0),
-                start_angle: Radians(self.first * step.0),
-                end_angle: Radians(size.0),
-            });
-            let out = builder.build();
-            frame.stroke(
-                &out,
-                stroke(width_ratio * style.tick_border_width_ratio, Color::BLACK),
-            );
+    pub fn boxed(length: f32) -> Box<Self> {
+        Box::new(Self::new(length))
+    }
+
+    #[must_use]
+    pub fn with_spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Major and minor steps for a range spread over an arc of the given pixel length
+    pub fn steps(&self, range: f32, arc: f32) -> (f32, f32) {
+        let count = (arc / self.spacing).clamp(2.0, 12.0);
+        let raw = f64::from(range / count);
+        let base = 10f64.powi(raw.log10().floor() as i32);
+        let (lead, minors) = match raw / base {
+            f if f <= 1.0 => (1.0, 5.0),
+            f if f <= 2.0 => (2.0, 4.0),
+            f if f <= 5.0 => (5.0, 5.0),
+            _ => (10.0, 5.0),
+        };
+        let major = lead * base;
+        (major as f32, (major / minors) as f32)
+    }
+}
+
+impl Marked for Auto {
+    fn marking_mut(&mut self) -> &mut Marking {
+        &mut self.ticks.marking
+    }
+}
+
+impl Tick for Auto {
+    fn draw(&self, frame: &mut Frame, style: &Appearance, dial: &Dial, ring: &Ellipse) {
+        let arc = dial.length.0 * ring.min_radius();
+        let (major_step, minor_step) = self.steps(dial.max - dial.min, arc);
+        self.ticks
+            .draw_steps(major_step, minor_step, frame, style, dial, ring);
+    }
+}
+
+/// Tick at a listed unit value
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub value: f32,
+    /// Drawn beside major ticks, an empty string draws no label
+    pub label: String,
+    pub major: bool,
+}
+
+/// Ticks at listed unit values, such as gear positions or fractions of a tank
+#[derive(Clone, Debug)]
+pub struct Explicit {
+    pub entries: Vec<Entry>,
+    /// Labels show the listed text when no formatter is set
+    pub marking: Marking,
+    /// Move the needle to the nearest listed value
+    pub snap: bool,
+}
+
+impl Explicit {
+    pub fn new(length: f32) -> Self {
+        Explicit {
+            entries: Vec::new(),
+            marking: Marking::new(length, length * 0.75),
+            snap: false,
+        }
+    }
+
+    pub fn boxed(length: f32) -> Box<Self> {
+        Box::new(Self::new(length))
+    }
+
+    /// Labeled major ticks at the unit values 0, 1, 2 and so on
+    pub fn categories<S: Into<String>>(length: f32, labels: impl IntoIterator<Item = S>) -> Self {
+        labels
+            .into_iter()
+            .enumerate()
+            .fold(Self::new(length), |ticks, (i, label)| {
+                ticks.major(i as f32, label)
+            })
+    }
+
+    #[must_use]
+    pub fn major(mut self, value: f32, label: impl Into<String>) -> Self {
+        self.entries.push(Entry {
+            value,
+            label: label.into(),
+            major: true,
         });
+        self
     }
+
+    #[must_use]
+    pub fn minor(mut self, value: f32) -> Self {
+        self.entries.push(Entry {
+            value,
+            label: String::new(),
+            major: false,
+        });
+        self
+    }
+
+    #[must_use]
+    pub fn with_snap(mut self, snap: bool) -> Self {
+        self.snap = snap;
+        self
+    }
+}
+
+impl Marked for Explicit {
+    fn marking_mut(&mut self) -> &mut Marking {
+        &mut self.marking
+    }
+}
+
+impl Tick for Explicit {
+    fn draw(&self, frame: &mut Frame, style: &Appearance, dial: &Dial, ring: &Ellipse) {
+        let painter = Painter::new(style, dial, ring, &self.marking);
+        let tolerance = (dial.max - dial.min) * 1.0E-4;
+        let in_range =
+            |e: &&Entry| e.value >= dial.min - tolerance && e.value <= dial.max + tolerance;
+        for entry in self.entries.iter().filter(in_range) {
+            let mark = self.marking.mark(entry.value, entry.major, tolerance);
+            if entry.major {
+                let label = match self.marking.label(entry.value) {
+                    Some(label) if !entry.label.is_empty() => label,
+                    _ => entry.label.clone(),
+                };
+                painter.major(frame, entry.value, mark, label);
+            } else {
+                painter.minor(frame, entry.value, mark);
+            }
+        }
+        painter.border(frame);
+    }
+
+    fn snap(&self, value: f32) -> Option<f32> {
+        if !self.snap {
+            return None;
+        }
+        self.entries
+            .iter()
+            .map(|e| e.value)
+            .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+    }
+}
+
+/// Second scale showing the gauge value in another unit, such as km/h inside mph
+pub struct TickRing {
+    pub ticks: Box<dyn Tick>,
+    /// Values on this ring are gauge values times the factor plus the offset
+    /// The factor must be positive, which [`crate::round::GaugeBuilder::build`] checks
+    pub factor: f32,
+    pub offset: f32,
+    /// Tick border as ratio of the gauge radius
+    pub inset_ratio: f32,
+    /// Appearance of this ring, the gauge style when not set
+    pub style: Option<Style>,
+}
+
+impl TickRing {
+    pub fn new(ticks: Box<dyn Tick>, factor: f32) -> Self {
+        TickRing {
+            ticks,
+            factor,
+            offset: 0.0,
+            inset_ratio: 0.55,
+            style: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    #[must_use]
+    pub fn with_inset(mut self, inset_ratio: f32) -> Self {
+        self.inset_ratio = inset_ratio;
+        self
+    }
+
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Draw the ring onto a frame centered and rotated to the gauge minimum
+    /// The dial is in gauge units and the face is the gauge outline in pixels
+    pub fn draw(&self, frame: &mut Frame, style: &Appearance, dial: &Dial, face: &Ellipse) {
+        let scale = Converted {
+            scale: dial.scale,
+            factor: self.factor,
+            offset: self.offset,
+        };
+        let converted = Dial {
+            min: scale.convert(dial.min),
+            max: scale.convert(dial.max),
+            length: dial.length,
+            rotate: dial.rotate,
+            scale: &scale,
+        };
+        self.ticks
+            .draw(frame, style, &converted, &face.scaled(self.inset_ratio));
+    }
+}
+
+#[test]
+fn fractional_steps_from_range_start() {
+    let ticks = MajorMinor::new(0.0, 0.1, 0.05, 0.3);
+    let positions: Vec<_> = ticks.positions(0.0, 1.0).collect();
+    assert_eq!(positions.len(), 21);
+    assert_eq!(positions.iter().filter(|(_, major)| *major).count(), 11);
+    assert_eq!(ticks.label(positions[6].0, 0.1), "0.3");
+
+    let ticks = MajorMinor::new(0.0, 25.0, 5.0, 0.3);
+    let first = ticks.positions(20.0, 120.0).next();
+    assert_eq!(first, Some((20.0, false)));
+    assert_eq!(ticks.positions(20.0, 120.0).filter(|(_, m)| *m).count(), 4);
+
+    // majors off the minor grid are kept between the minors
+    let ticks = MajorMinor::new(0.0, 2.5, 1.0, 0.3);
+    let majors: Vec<_> = ticks
+        .positions(0.0, 10.0)
+        .filter(|(_, major)| *major)
+        .map(|(v, _)| v)
+        .collect();
+    assert_eq!(majors, [0.0, 2.5, 5.0, 7.5, 10.0]);
+    assert_eq!(ticks.positions(0.0, 10.0).count(), 5 + 8);
+
+    let ticks = ticks.with_formatter(|v: f32| format!("{v} km"));
+    let debug = format!("{ticks:?}");
+    assert!(debug.contains("major_step: 2.5") && debug.contains("formatter: Some(..)"));
+}
+
+#[test]
+fn logarithmic_decades() {
+    let ticks = Logarithmic::new(0.3);
+    let positions: Vec<_> = ticks.positions(0.001, 1.0).collect();
+    assert_eq!(positions.iter().filter(|(_, major)| *major).count(), 4);
+    assert_eq!(positions.len(), 4 + 3 * 8);
+    assert_eq!(ticks.label(0.001), "10⁻³");
+    assert_eq!(ticks.label(100.0), "10²");
+
+    let ticks = ticks.with_mark_at(200.0, Marks::Dot);
+    let listed = ticks.marking.marks_at[0].1.as_ref();
+    assert!(std::ptr::addr_eq(
+        ticks.marking.mark(200.0, false, 0.2),
+        listed
+    ));
+    assert!(!std::ptr::addr_eq(
+        ticks.marking.mark(300.0, false, 0.3),
+        listed
+    ));
+}
+
+#[test]
+fn auto_steps_follow_size() {
+    let ticks = Auto::new(0.3);
+    let arc = |radius: f32| 0.75 * std::f32::consts::TAU * radius;
+    assert_eq!(ticks.steps(100.0, arc(200.0)), (10.0, 2.0));
+    assert_eq!(ticks.steps(100.0, arc(80.0)), (20.0, 5.0));
+    assert_eq!(ticks.steps(1.0, arc(200.0)), (0.1, 0.02));
+}
+
+#[test]
+fn explicit_snaps_to_nearest_entry() {
+    let ticks = Explicit::categories(0.3, ["P", "R", "N", "D", "L"]);
+    assert_eq!(ticks.entries[3].label, "D");
+    assert_eq!(ticks.snap(2.4), None);
+    let ticks = ticks.minor(2.5).with_snap(true);
+    assert_eq!(ticks.snap(2.4), Some(2.5));
+    assert_eq!(ticks.snap(-3.0), Some(0.0));
+}
+
+#[test]
+fn large_ranges_visit_only_ticks() {
+    let ticks = MajorMinor::new(0.0, 1000.0, 100.0, 0.3);
+    let positions: Vec<_> = ticks.positions(0.0, 10_000.0).collect();
+    assert_eq!(positions.len(), 101);
+    assert_eq!(positions.iter().filter(|(_, major)| *major).count(), 11);
+    assert_eq!(positions.last(), Some(&(10_000.0, true)));
+
+    let ticks = MajorMinor::new(1.0E6, 1.0, 0.25, 0.3);
+    let positions: Vec<_> = ticks.positions(1.0E6, 1.0E6 + 100.0).collect();
+    assert_eq!(positions.len(), 401);
+    assert_eq!(positions[398], (1.0E6 + 99.5, false));
 }
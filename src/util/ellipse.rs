@@ -1,8 +1,11 @@
 use std::f32::consts::PI;
 
+use iced::widget::canvas::path::Builder;
 use iced::Point;
 
 const EPS: f32 = 1.0E-6;
+/// Largest angle covered by one line segment when tracing the outline
+const TRACE_STEP: f32 = PI / 180.0;
 type Angle = f32;
 
 #[derive(Default, Copy, Clone)]
@@ -23,6 +26,69 @@ impl Ellipse {
         }
     }
 
+    /// Axis aligned ellipse with the given semi axes
+    pub fn oval(rx: f32, ry: f32) -> Self {
+        Ellipse {
+            center: Point::ORIGIN,
+            major_curvature: 1.0 / rx,
+            minor_radius: ry,
+            angle: 0.0,
+        }
+    }
+
+    /// Same shape with both axes multiplied by the ratio
+    #[must_use]
+    pub fn scaled(&self, ratio: f32) -> Self {
+        Ellipse {
+            major_curvature: self.major_curvature / ratio,
+            minor_radius: self.minor_radius * ratio,
+            ..*self
+        }
+    }
+
+    /// Same shape turned by the angle
+    #[must_use]
+    pub fn rotated(&self, angle: Angle) -> Self {
+        Ellipse {
+            angle: self.angle + angle,
+            ..*self
+        }
+    }
+
+    /// Smallest distance from the center to the outline
+    pub fn min_radius(&self) -> f32 {
+        self.minor_radius.min(1.0 / self.major_curvature.abs())
+    }
+
+    /// Distance from the center to the outline in the direction of the angle
+    pub fn radius_at(&self, angle: Angle) -> f32 {
+        let (sin, cos) = (angle - self.angle).sin_cos();
+        let x = self.minor_radius * self.major_curvature * cos;
+        self.minor_radius / (x * x + sin * sin).sqrt()
+    }
+
+    /// Point on the outline in the direction of the angle
+    ///
+    /// Unlike [`Ellipse::get_point`] the angle is measured from the center,
+    /// so lines between outlines of scaled ellipses point at the center.
+    pub fn get_polar_point(&self, angle: Angle) -> Point {
+        let r = self.radius_at(angle);
+        Point::new(
+            self.center.x + r * angle.cos(),
+            self.center.y + r * angle.sin(),
+        )
+    }
+
+    /// Add the outline between two directions to the path, starting a new sub path
+    pub fn trace(&self, builder: &mut Builder, start: Angle, end: Angle) {
+        let segments = ((end - start).abs() / TRACE_STEP).ceil().max(1.0) as usize;
+        builder.move_to(self.get_polar_point(start));
+        for i in 1..=segments {
+            let angle = start + (end - start) * i as f32 / segments as f32;
+            builder.line_to(self.get_polar_point(angle));
+        }
+    }
+
     pub fn get_point(&self, angle: Angle) -> Point {
         let pt = self.get_relative_point(angle);
         Point::new(self.center.x + pt.0, self.center.y + pt.1)
@@ -60,3 +126,14 @@ fn get_point() {
     let t = outer.get_point(0.0);
     println!("{t:?}");
 }
+
+#[test]
+fn radius_at_follows_axes() {
+    let oval = Ellipse::oval(200.0, 100.0);
+    assert!((oval.radius_at(0.0) - 200.0).abs() < 1.0E-3);
+    assert!((oval.radius_at(PI / 2.0) - 100.0).abs() < 1.0E-3);
+    let turned = oval.rotated(PI / 2.0);
+    assert!((turned.radius_at(PI / 2.0) - 200.0).abs() < 1.0E-3);
+    assert!((Ellipse::round(50.0).radius_at(1.0) - 50.0).abs() < 1.0E-3);
+    assert_eq!(oval.scaled(0.5).min_radius(), 50.0);
+}